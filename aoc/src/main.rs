@@ -0,0 +1,121 @@
+use std::time::Duration;
+
+use miette::{miette, Context, IntoDiagnostic, Result};
+use solution::{Part, Registered};
+
+const SOLUTIONS: &[&dyn Registered] = &[
+    &day1::Day,
+    &day2::Day,
+    &day3::Day,
+    &day4::Day,
+    &day5::Day,
+    &day6::Day,
+    &day7::Day,
+    &day8::Day,
+    &day9::Day,
+    &day10::Day,
+    &day11::Day,
+    &day12::Day,
+    &day13::Day,
+    &day14::Day,
+    &day15::Day,
+    &day16::Day,
+    &day17::Day,
+    &day18::Day,
+    &day19::Day,
+    &day20::Day,
+    &day21::Day,
+    &day22::Day,
+    &day23::Day,
+    &day24::Day,
+    &day25::Day,
+];
+
+const USAGE: &str = "usage: aoc <day> [part] [input path], or aoc all";
+
+fn find(day: &str) -> Result<&'static dyn Registered> {
+    let day = day
+        .parse::<u8>()
+        .map_err(|_| miette!(help = USAGE, "expected a day or `all`, found {day:?}"))?;
+    SOLUTIONS
+        .iter()
+        .copied()
+        .find(|solution| solution.day() == day)
+        .ok_or_else(|| miette!("day {day} is not registered"))
+}
+
+fn parse_part(part: &str) -> Result<Part> {
+    match part {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(miette!(
+            help = USAGE,
+            "expected part 1 or 2, found {part:?}"
+        )),
+    }
+}
+
+/// runs and prints one part, returning how long it took
+fn run(solution: &dyn Registered, input: &str, part: Part) -> Result<Duration> {
+    let day = solution.day();
+    let timed = solution
+        .run(input, part)
+        .wrap_err_with(|| format!("day {day} part {part} failed"))?;
+    println!(
+        "day {day:>2} part {part}: {:<16} (parse {:.2?}, solve {:.2?})",
+        timed.answer, timed.parse, timed.solve
+    );
+    Ok(timed.total())
+}
+
+/// runs every registered part, reporting failures without stopping
+fn run_all() -> Result<()> {
+    let mut total = Duration::ZERO;
+    let mut failures = 0;
+    for &solution in SOLUTIONS {
+        for &part in solution.parts() {
+            match run(solution, solution.input(), part) {
+                Ok(elapsed) => total += elapsed,
+                Err(report) => {
+                    failures += 1;
+                    eprintln!("{report:?}");
+                }
+            }
+        }
+    }
+    println!("total: {total:.2?}");
+
+    if failures > 0 {
+        Err(miette!("{failures} parts failed"))
+    } else {
+        Ok(())
+    }
+}
+
+fn main() -> Result<()> {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+
+    match args[..] {
+        ["all"] => run_all(),
+        [day, ref rest @ ..] if rest.len() <= 2 => {
+            let solution = find(day)?;
+            let parts = match rest.first() {
+                Some(part) => vec![parse_part(part)?],
+                None => solution.parts().to_vec(),
+            };
+            let input = match rest.get(1) {
+                Some(path) => std::fs::read_to_string(path)
+                    .into_diagnostic()
+                    .wrap_err_with(|| format!("could not read input from {path}"))?,
+                None => solution.input().to_string(),
+            };
+
+            for part in parts {
+                run(solution, &input, part)?;
+            }
+            Ok(())
+        }
+        _ => Err(miette!(USAGE)),
+    }
+}
@@ -1,8 +1,3 @@
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part1(input));
-}
-
 pub fn part1(input: &str) -> String {
     input
         .lines()
@@ -1,10 +1,5 @@
 use regex::Regex;
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part2(input));
-}
-
 fn parse(s: &str) -> i32 {
     match s {
         "one" => 1,
@@ -27,13 +22,12 @@ pub fn part2(input: &str) -> String {
         .lines()
         .map(|line| {
             // get all the numbers in the line
-            dbg!(line);
             let first = re.find(line).unwrap().as_str();
             let last = end_re
                 .captures(line)
                 .map(|m| m.get(1).unwrap().as_str())
                 .unwrap_or(first);
-            dbg!(format!("{}{}", parse(first), parse(last)))
+            format!("{}{}", parse(first), parse(last))
         })
         .fold(0, |acc, x| acc + x.parse::<i32>().unwrap())
         .to_string()
@@ -5,11 +5,6 @@ use miette_pretty::Pretty;
 use parse::{parse_grid, Grid, QuickRegex, Relationship};
 use petgraph::{algo::dijkstra, graphmap::GraphMap, Directed, EdgeType};
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part1(input).unwrap());
-}
-
 /// | is a vertical pipe connecting north and south.
 /// - is a horizontal pipe connecting east and west.
 /// L is a 90-degree bend connecting north and east.
@@ -12,11 +12,6 @@ use petgraph::{
     Directed,
 };
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part2(input).unwrap());
-}
-
 /// | is a vertical pipe connecting north and south.
 /// - is a horizontal pipe connecting east and west.
 /// L is a 90-degree bend connecting north and east.
@@ -131,8 +126,6 @@ pub fn part2(input: &str) -> Result<i64> {
         vec![],
     );
 
-    dbg!(pipe_coords.len());
-
     let mut contained = 0;
     for x in 0..grid.width {
         for y in 0..grid.height {
@@ -0,0 +1,28 @@
+use miette::Result;
+use solution::Solution;
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day;
+
+impl Solution for Day {
+    const DAY: u8 = 11;
+    const INPUT: &'static str = include_str!("../input.txt");
+
+    type Parsed<'a> = &'a str;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<&str> {
+        Ok(input)
+    }
+
+    fn part1(input: &&str) -> Result<i64> {
+        part1::part1(input)
+    }
+
+    fn part2(input: &&str) -> Result<i64> {
+        part2::part2(input, 1_000_000)
+    }
+}
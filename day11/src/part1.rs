@@ -5,11 +5,6 @@ use parse::{parse_grid, Grid, QuickRegex};
 use petgraph::{algo::astar, Undirected};
 use rayon::iter::{ParallelBridge, ParallelIterator};
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part1(input).unwrap());
-}
-
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
 enum Tile {
     E,
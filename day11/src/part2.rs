@@ -4,11 +4,6 @@ use miette_pretty::Pretty;
 use parse::parse_grid;
 use rayon::iter::{ParallelBridge, ParallelIterator};
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part2(input, 1_000_000).unwrap());
-}
-
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
 enum Tile {
     E,
@@ -40,7 +35,6 @@ fn expand_gaps(coords: &[(usize, usize)], expansion: usize) -> Vec<(usize, usize
             *y,
         ));
         last_x = *x;
-        dbg!(new_coords.last().unwrap().0);
     }
 
     let mut final_coords = vec![];
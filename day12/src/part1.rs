@@ -4,11 +4,6 @@ use miette_pretty::Pretty;
 use parse::QuickRegex;
 use rayon::iter::{ParallelBridge, ParallelIterator};
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part1(input).unwrap());
-}
-
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 enum State {
     Operational,
@@ -97,7 +92,7 @@ pub fn part1(input: &str) -> Result<i64> {
     let parsed = parse(input)?;
     Ok(parsed
         .iter()
-        .map(|(c, d)| dbg!(compute_possible_arrangements(c, d)))
+        .map(|(c, d)| compute_possible_arrangements(c, d))
         .sum())
 }
 
@@ -7,11 +7,6 @@ use miette_pretty::Pretty;
 use parse::QuickRegex;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part2(input, 5).unwrap());
-}
-
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
 enum State {
     Operational,
@@ -242,12 +237,12 @@ pub fn part2(input: &str, repeats: usize) -> Result<i64> {
     Ok(parsed
         .par_iter()
         .map(|(c, d)| {
-            dbg!(compute_possible_arrangements(
+            compute_possible_arrangements(
                 c.clone(),
                 &build_damage_signature(c),
                 d,
-                &FrozenMap::new()
-            ))
+                &FrozenMap::new(),
+            )
         })
         .sum())
 }
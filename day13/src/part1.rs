@@ -6,11 +6,6 @@ use miette::Result;
 use miette_pretty::Pretty;
 use parse::{parse_grid, Grid, QuickRegex};
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part1(input).unwrap());
-}
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum Tile {
     Ash,
@@ -74,7 +69,6 @@ fn find_mirror(grid: &Grid<Tile>) -> Axis {
         }
     }
 
-    dbg!(grid);
     unreachable!("no mirror found")
 }
 
@@ -83,11 +77,9 @@ pub fn part1(input: &str) -> Result<usize> {
     Ok(parsed
         .iter()
         .map(find_mirror)
-        .map(|a| {
-            dbg!(match a {
-                Axis::Y(y) => dbg!(y) * 100,
-                Axis::X(x) => dbg!(x),
-            })
+        .map(|a| match a {
+            Axis::Y(y) => y * 100,
+            Axis::X(x) => x,
         })
         .sum())
 }
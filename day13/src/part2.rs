@@ -7,11 +7,6 @@ use miette_pretty::Pretty;
 use parse::{parse_grid, Grid, QuickRegex};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part2(input).unwrap());
-}
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum Tile {
     Ash,
@@ -5,11 +5,6 @@ use parse::{QuickRegex, Tile};
 
 use parse::Grid;
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part1(input).unwrap());
-}
-
 Tile! {
     Round = 'O',
     Cube = '#',
@@ -47,9 +42,7 @@ fn compute_load(grid: &Grid<Tile>) -> i64 {
 
 pub fn part1(input: &str) -> Result<i64> {
     let mut grid = parse(input)?;
-    dbg!(&grid);
     roll_rocks(&mut grid);
-    dbg!(&grid);
     Ok(compute_load(&grid))
 }
 
@@ -7,11 +7,6 @@ use parse::{QuickRegex, Tile};
 
 use parse::Grid;
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part2(input).unwrap());
-}
-
 Tile! {
     Round = 'O',
     Cube = '#',
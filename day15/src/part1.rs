@@ -3,11 +3,6 @@ use miette::Result;
 use miette_pretty::Pretty;
 use parse::QuickRegex;
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part1(input).unwrap());
-}
-
 fn hash_algorithm(input: &str) -> u64 {
     let mut value = 0;
     for c in input.chars() {
@@ -26,10 +21,7 @@ fn parse(input: &str) -> Result<Vec<&str>> {
 
 pub fn part1(input: &str) -> Result<u64> {
     let parsed = parse(input)?;
-    Ok(parsed
-        .iter()
-        .map(|s| dbg!(hash_algorithm(dbg!(s))))
-        .sum::<u64>())
+    Ok(parsed.iter().map(|s| hash_algorithm(s)).sum::<u64>())
 }
 
 #[cfg(test)]
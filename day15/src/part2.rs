@@ -2,11 +2,6 @@ use std::collections::HashMap;
 
 use itertools::Itertools;
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part2(input));
-}
-
 fn hash_algorithm(input: &str) -> usize {
     let mut value = 0;
     for c in input.chars() {
@@ -32,7 +27,6 @@ pub fn part2(input: &str) -> usize {
     let mut boxes: Vec<Vec<(&str, usize)>> = vec![Vec::new(); 256];
     for (label, val) in parsed {
         let hash = hash_algorithm(label);
-        dbg!((label, val));
         match val {
             Some(val) => {
                 if let Some(i) = boxes[hash].iter().position(|(l, _)| *l == label) {
@@ -10,11 +10,6 @@ use miette_pretty::Pretty;
 use parse::Grid;
 use parse::{QuickRegex, Tile};
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part1(input).unwrap());
-}
-
 Tile! {
     Empty = '.',
     HorizontalSplitter = '-',
@@ -45,7 +40,6 @@ pub fn part1(input: &str) -> Result<usize> {
         HashSet::with_capacity(grid.width * grid.height);
 
     while !beams.is_empty() {
-        dbg!(beams.len());
         for i in 0..beams.len() {
             let ((x, y), direction) = beams[i];
             let usize_x = x.try_into();
@@ -11,11 +11,6 @@ use parse::Grid;
 use parse::{QuickRegex, Tile};
 use rayon::iter::{ParallelBridge, ParallelIterator};
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part2(input).unwrap());
-}
-
 Tile! {
     Empty = '.',
     HorizontalSplitter = '-',
@@ -7,11 +7,6 @@ use petgraph::{graphmap::GraphMap, Directed};
 use strum::{self, IntoEnumIterator};
 use strum_macros::EnumIter;
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part1(input).unwrap());
-}
-
 type G = Grid<u64>;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, PartialOrd, Ord)]
@@ -108,13 +103,6 @@ pub fn part1(input: &str) -> Result<u64> {
             });
     }
 
-    dbg!(graph.node_count());
-
-    dbg!(graph
-        .neighbors(origins[0])
-        .map(|n| graph.neighbors(n).count())
-        .sum::<usize>());
-
     origins
         .iter()
         .map(|&origin| {
@@ -9,11 +9,6 @@ use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use strum::{self, IntoEnumIterator};
 use strum_macros::EnumIter;
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part2(input).unwrap());
-}
-
 type G = Grid<u64>;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, PartialOrd, Ord)]
@@ -4,11 +4,6 @@ use miette::Result;
 use miette_pretty::Pretty;
 use parse::{Grid, QuickRegex, Tile};
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part1(input).unwrap());
-}
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 enum Direction {
     Up,
@@ -75,8 +70,6 @@ pub fn part1(input: &str) -> Result<u64> {
     // use picks theorem to calculate area
 
     let area = polygon.unsigned_area();
-    dbg!(boundary_points);
-    dbg!(area);
     let interior_points = area + (boundary_points as f64 / 2.0) + 1.0;
 
     Ok(interior_points as u64)
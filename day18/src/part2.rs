@@ -4,11 +4,6 @@ use miette::Result;
 use miette_pretty::Pretty;
 use parse::{Grid, QuickRegex, Tile};
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part2(input).unwrap());
-}
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 enum Direction {
     Up,
@@ -26,14 +21,14 @@ fn parse(input: &str) -> Result<Vec<(Direction, i64)>> {
             let color = color.trim_start_matches("(#").trim_end_matches(')');
             // #70c710 = R 461937
             let dist: i64 = i64::from_str_radix(&color[0..5], 16).unwrap();
-            let direction = match dbg!(color.chars().last().unwrap()) {
+            let direction = match color.chars().last().unwrap() {
                 '0' => Direction::Right,
                 '1' => Direction::Down,
                 '2' => Direction::Left,
                 '3' => Direction::Up,
                 _ => unreachable!(),
             };
-            Ok(dbg!((direction, dist)))
+            Ok((direction, dist))
         })
         .collect()
 }
@@ -74,8 +69,6 @@ pub fn part2(input: &str) -> Result<u64> {
     // use picks theorem to calculate area
 
     let area = polygon.unsigned_area();
-    dbg!(boundary_points);
-    dbg!(area);
     let interior_points = area + (boundary_points as f64 / 2.0) + 1.0;
 
     Ok(interior_points as u64)
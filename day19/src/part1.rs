@@ -6,11 +6,6 @@ use miette::Result;
 use miette_pretty::Pretty;
 use parse::{Grid, QuickRegex, Tile};
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part1(input).unwrap());
-}
-
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 struct Part {
     x: u64,
@@ -6,11 +6,6 @@ use miette_pretty::Pretty;
 use parse::pattern_enum;
 use util::RangeSet;
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part2(input).unwrap());
-}
-
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 struct Part {
     x: u64,
@@ -4,11 +4,6 @@ use itertools::Itertools;
 use num::{integer::lcm, Integer};
 use petgraph::{graphmap::GraphMap, visit::IntoEdgeReferences, Directed};
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part2(input));
-}
-
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
 enum Pulse {
     Low,
@@ -0,0 +1,30 @@
+use miette::{miette, Result};
+use solution::{Part, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day;
+
+impl Solution for Day {
+    const DAY: u8 = 21;
+    const INPUT: &'static str = include_str!("../input.txt");
+    /// part 2 is not finished, see [`part2::part2`]
+    const PARTS: &'static [Part] = &[Part::One];
+
+    type Parsed<'a> = &'a str;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<&str> {
+        Ok(input)
+    }
+
+    fn part1(input: &&str) -> Result<u64> {
+        part1::part1(input, 64)
+    }
+
+    fn part2(_input: &&str) -> Result<u64> {
+        Err(miette!("day 21 part 2 is not finished"))
+    }
+}
@@ -5,11 +5,6 @@ use miette_pretty::Pretty;
 use parse::{pattern_enum, Grid, QuickRegex, Tile};
 use petgraph::{graphmap::GraphMap, Directed, Undirected};
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part1(input, 64).unwrap());
-}
-
 /// Start counts as a GardenPlot
 Tile! {
     Start = 'S',
@@ -1,16 +1,10 @@
 use std::collections::{HashMap, HashSet};
 
-use itertools::Itertools;
 use miette::Result;
 use miette_pretty::Pretty;
 use parse::{pattern_enum, Grid, Tile};
 use petgraph::{graphmap::GraphMap, Undirected};
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part2(input, 26501365).unwrap());
-}
-
 /// Start counts as a GardenPlot
 Tile! {
     Start = 'S',
@@ -87,18 +81,13 @@ fn reachable_after_n(
         .sum::<usize>() as u64
 }
 
-pub fn part2(input: &str, steps: u64) -> Result<u64> {
+pub fn part2(input: &str, _steps: u64) -> Result<u64> {
     let grid = parse(input)?;
     let starts = grid.lookup(Tile::Start);
     assert!(starts.len() == 1);
     let start = starts[0];
     let mut graph = GraphMap::<(usize, usize), (), Undirected>::new();
 
-    dbg!(grid.width);
-    dbg!(steps % grid.width as u64);
-
-    dbg!(start);
-
     let mut frontier = vec![start];
 
     while let Some(current) = frontier.pop() {
@@ -126,11 +115,6 @@ pub fn part2(input: &str, steps: u64) -> Result<u64> {
         vals.push((at, val));
     }
 
-    dbg!(vals
-        .iter()
-        .map(|(at, val)| format!("{{ {at}, {val} }}"))
-        .join(", "));
-
     // https://www.wolframalpha.com/input?i=quadratic+regression&assumption=%7B%22F%22%2C+%22QuadraticFitCalculator%22%2C+%22data2%22%7D+-%3E%22%7B%7B+65%2C+3791+%7D%2C+%7B+196%2C+33646+%7D%2C+%7B+327%2C+93223+%7D%7D%22
 
     todo!()
@@ -6,11 +6,6 @@ use miette_pretty::Pretty;
 use parse::QuickRegex;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part1(input).unwrap());
-}
-
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 struct Pos(i64, i64, i64);
 
@@ -206,7 +201,6 @@ pub fn part1(input: &str) -> Result<i64> {
             }
         }
     }
-    dbg!(max_x, max_y, max_z);
 
     for (i, start, end) in &bricks {
         for pos in start.iter_to(*end) {
@@ -9,11 +9,6 @@ use miette_pretty::Pretty;
 use parse::QuickRegex;
 use rayon::iter::{ParallelBridge, ParallelIterator};
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part2(input).unwrap());
-}
-
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 struct Pos(i64, i64, i64);
 
@@ -4,11 +4,6 @@ use miette_pretty::Pretty;
 use parse::{Grid, QuickRegex, Tile};
 use petgraph::{algo::all_simple_paths, graphmap::GraphMap, Directed};
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part1(input).unwrap());
-}
-
 Tile! {
     Path = '.',
     Forest = '#',
@@ -60,10 +55,7 @@ pub fn part1(input: &str) -> Result<u64> {
         .unwrap()
         .clone();
 
-    dbg!(start, end);
-
     let paths = all_simple_paths::<Vec<_>, _>(&graph, start, end, 0, None).collect_vec();
-    dbg!(paths.len());
     paths.iter().map(|p| p.len() as u64 - 1).max().pretty()
 }
 
@@ -4,11 +4,6 @@ use miette_pretty::Pretty;
 use parse::{Grid, QuickRegex, Tile};
 use petgraph::{algo::all_simple_paths, graphmap::GraphMap, Directed};
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part2(input).unwrap());
-}
-
 Tile! {
     Path = '.',
     Forest = '#',
@@ -50,14 +45,11 @@ pub fn part2(input: &str) -> Result<u64> {
         .unwrap()
         .clone();
 
-    dbg!(start, end);
-
     let mut max_len = 0;
     all_simple_paths::<Vec<_>, _>(&graph, start, end, 6221, None).for_each(|path| {
         let len = path.len() as u64 - 1;
         if len > max_len {
             max_len = len;
-            dbg!(max_len);
         }
     });
 
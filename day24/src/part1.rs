@@ -7,11 +7,6 @@ use miette_pretty::Pretty;
 use num::Float;
 use parse::QuickRegex;
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part1(input, 200000000000000..=400000000000000).unwrap());
-}
-
 type Triple = (i64, i64, i64);
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
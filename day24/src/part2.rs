@@ -11,11 +11,6 @@ use z3::Config;
 use z3::Context;
 use z3::Solver;
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part2(input, 200000000000000..=400000000000000).unwrap());
-}
-
 type Triple = (i64, i64, i64);
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
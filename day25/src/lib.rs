@@ -0,0 +1,28 @@
+use miette::{miette, Result};
+use solution::{Part, Solution};
+
+pub mod part1;
+
+pub struct Day;
+
+impl Solution for Day {
+    const DAY: u8 = 25;
+    const INPUT: &'static str = include_str!("../input.txt");
+    const PARTS: &'static [Part] = &[Part::One];
+
+    type Parsed<'a> = &'a str;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<&str> {
+        Ok(input)
+    }
+
+    fn part1(input: &&str) -> Result<i64> {
+        part1::part1(input)
+    }
+
+    fn part2(_input: &&str) -> Result<i64> {
+        Err(miette!("day 25 has no part 2"))
+    }
+}
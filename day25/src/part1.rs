@@ -11,11 +11,6 @@ use petgraph::{
 };
 use rand::seq::SliceRandom;
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part1(input).unwrap());
-}
-
 fn parse(input: &str) -> Result<Vec<(&str, Vec<&str>)>> {
     input
         .lines()
@@ -6,11 +6,6 @@ use miette::Result;
 // add pretty and pretty_msg to Result to show the line number where the error occurred
 use miette_pretty::Pretty;
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part1(input).unwrap());
-}
-
 #[derive(Debug)]
 struct JoinedNumber {
     x_range: (usize, usize),
@@ -83,8 +78,6 @@ fn parse(input: &str) -> Result<(Vec<JoinedNumber>, Vec<(usize, usize)>)> {
         });
     }
 
-    dbg!(&joined_numbers);
-
     Ok((joined_numbers, symbol_cords))
 }
 
@@ -108,8 +101,6 @@ pub fn part1(input: &str) -> Result<u64> {
         })
         .collect::<Vec<_>>();
 
-    dbg!(&adjacent_numbers);
-
     Ok(adjacent_numbers
         .iter()
         .map(|n| n.n)
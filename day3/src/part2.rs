@@ -4,11 +4,6 @@ use miette::Result;
 // add pretty and pretty_msg to Result to show the line number where the error occurred
 use miette_pretty::Pretty;
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part2(input).unwrap());
-}
-
 #[derive(Debug)]
 struct JoinedNumber {
     x_range: (usize, usize),
@@ -33,8 +28,6 @@ fn parse(input: &str) -> Result<(Vec<JoinedNumber>, Vec<(usize, usize)>)> {
         })
         .collect::<Vec<_>>();
 
-    dbg!(&symbol_cords);
-
     let numbers = lines
         .iter()
         .enumerate()
@@ -83,8 +76,6 @@ fn parse(input: &str) -> Result<(Vec<JoinedNumber>, Vec<(usize, usize)>)> {
         });
     }
 
-    dbg!(&joined_numbers);
-
     Ok((joined_numbers, symbol_cords))
 }
 
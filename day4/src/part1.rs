@@ -6,11 +6,6 @@ use miette::Result;
 use miette_pretty::Pretty;
 use parse::QuickRegex;
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part1(input).unwrap());
-}
-
 fn parse(input: &str) -> Result<Vec<(HashSet<i64>, HashSet<i64>)>> {
     input
         .lines()
@@ -5,11 +5,6 @@ use miette::Result;
 // add pretty and pretty_msg to Result to show the line number where the error occurred
 use miette_pretty::Pretty;
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part2(input).unwrap());
-}
-
 fn parse(input: &str) -> Result<Vec<(HashSet<u64>, HashSet<u64>)>> {
     Ok(input
         .lines()
@@ -36,13 +31,11 @@ pub fn part2(input: &str) -> Result<u64> {
         .map(|(w, m)| w.intersection(m).count())
         .collect::<Vec<_>>();
     let mut copies = vec![1; wins.len()];
-    dbg!(&wins);
     for i in 0..wins.len() {
         for j in (i + 1)..(i + wins[i] + 1) {
             copies[j] += copies[i];
         }
     }
-    dbg!(&copies);
 
     Ok(copies.iter().sum())
 }
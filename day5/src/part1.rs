@@ -2,11 +2,6 @@ use miette::Result;
 use miette_pretty::Pretty;
 use parse::QuickRegex;
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part1(input).unwrap());
-}
-
 #[derive(Debug)]
 struct Map {
     dest_range_start: i64,
@@ -45,7 +40,6 @@ fn parse(input: &str) -> Result<(Vec<i64>, Maps)> {
                 return Ok(MapLine::MapType(line.get_match("[\\w\\-]+")?.to_string()));
             }
             let nums = line.get_digits()?;
-            dbg!(&line);
             Ok(MapLine::Map(Map {
                 dest_range_start: nums[0],
                 source_range_start: nums[1],
@@ -123,7 +117,6 @@ pub fn part1(input: &str) -> Result<i64> {
         .iter()
         .map(|seed| lookup_final_location(*seed))
         .collect::<Vec<_>>();
-    dbg!(&locations);
     locations.iter().min().pretty().copied()
 }
 
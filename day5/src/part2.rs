@@ -6,11 +6,6 @@ use miette_pretty::Pretty;
 use parse::QuickRegex;
 use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part2(input).unwrap());
-}
-
 #[derive(Debug)]
 struct Map {
     dest_range_start: i64,
@@ -125,13 +120,10 @@ fn lookup_final_location(maps: &Maps, seed: i64) -> i64 {
 
 pub fn part2(input: &str) -> Result<i64> {
     let parsed = parse(input)?;
-    dbg!(parsed.0.len());
-    dbg!(parsed.1.seed_to_soil.len());
     parsed
         .0
         .iter()
         .map(|seeds| {
-            dbg!(&seeds);
             seeds
                 .clone()
                 .into_par_iter()
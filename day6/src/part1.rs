@@ -4,11 +4,6 @@ use miette::Result;
 use miette_pretty::Pretty;
 use parse::QuickRegex;
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part1(input).unwrap());
-}
-
 fn parse(input: &str) -> Result<Vec<(i64, i64)>> {
     let digits: Vec<Vec<i64>> = input
         .lines()
@@ -23,8 +18,6 @@ fn find_choices(race: (i64, i64)) -> Vec<i64> {
     (1..time)
         .filter_map(|hold_time| {
             let distance = hold_time * (time - hold_time);
-            dbg!(hold_time);
-            dbg!(distance);
             if distance > record {
                 Some(hold_time)
             } else {
@@ -5,11 +5,6 @@ use miette_pretty::Pretty;
 use parse::QuickRegex;
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part2(input).unwrap());
-}
-
 fn parse(input: &str) -> Result<(i64, i64)> {
     let digits: Vec<i64> = input
         .lines()
@@ -4,11 +4,6 @@ use miette::Result;
 use miette_pretty::Pretty;
 use parse::QuickRegex;
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part1(input).unwrap());
-}
-
 fn get_val(c: &char) -> i64 {
     match c {
         'A' => 14,
@@ -53,7 +48,6 @@ fn parse(input: &str) -> Result<Vec<(Vec<char>, i64)>> {
 
 pub fn part1(input: &str) -> Result<i64> {
     let parsed = parse(input)?;
-    dbg!(&parsed);
     Ok(parsed
         .iter()
         .sorted_by(|a, b| {
@@ -62,17 +56,13 @@ pub fn part1(input: &str) -> Result<i64> {
             if a_hand_type == b_hand_type {
                 let a_vals: Vec<_> = a.0.iter().map(|c| get_val(c)).collect();
                 let b_vals: Vec<_> = b.0.iter().map(|c| get_val(c)).collect();
-                dbg!((&a_vals, &b_vals));
-                dbg!(a_vals.cmp(&b_vals))
+                a_vals.cmp(&b_vals)
             } else {
                 a_hand_type.cmp(&b_hand_type)
             }
         })
         .enumerate()
-        .map(|(i, (hand, val))| {
-            dbg!((i, hand, val));
-            (i + 1) as i64 * val
-        })
+        .map(|(i, (_, val))| (i + 1) as i64 * val)
         .sum::<i64>())
 }
 
@@ -5,15 +5,10 @@ use miette::Result;
 use miette_pretty::Pretty;
 use parse::QuickRegex;
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part1(input).unwrap());
-}
-
 fn parse(input: &str) -> Result<(Vec<Direction>, HashMap<&str, (&str, &str)>)> {
     let (directions, map) = input.split_once("\n\n").pretty()?;
 
-    let directions = dbg!(directions)
+    let directions = directions
         .chars()
         .map(|s| match s {
             'L' => Direction::Left,
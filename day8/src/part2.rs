@@ -7,15 +7,10 @@ use num::integer::lcm;
 use parse::QuickRegex;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part2(input).unwrap());
-}
-
 fn parse(input: &str) -> Result<(Vec<Direction>, HashMap<&str, (&str, &str)>)> {
     let (directions, map) = input.split_once("\n\n").pretty()?;
 
-    let directions = dbg!(directions)
+    let directions = directions
         .chars()
         .map(|s| match s {
             'L' => Direction::Left,
@@ -61,7 +56,6 @@ fn step<'a>(
 
 pub fn part2(input: &str) -> Result<u64> {
     let (directions, map) = parse(input)?;
-    dbg!((&directions, &map));
     let nodes_ending_in_a = map.keys().filter(|key| key.ends_with('A')).collect_vec();
     let cycle_time = nodes_ending_in_a
         .par_iter()
@@ -75,7 +69,7 @@ pub fn part2(input: &str) -> Result<u64> {
                 }
                 steps += 1;
             }
-            Ok(dbg!(steps))
+            Ok(steps)
         })
         .collect::<Result<Vec<_>>>()?;
 
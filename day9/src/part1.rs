@@ -3,11 +3,6 @@ use miette::Result;
 use miette_pretty::Pretty;
 use parse::QuickRegex;
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part1(input).unwrap());
-}
-
 fn parse(input: &str) -> Result<Vec<Vec<i64>>> {
     input.lines().map(|l| l.get_digits()).collect()
 }
@@ -3,11 +3,6 @@ use miette::Result;
 use miette_pretty::Pretty;
 use parse::QuickRegex;
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part2(input).unwrap());
-}
-
 fn parse(input: &str) -> Result<Vec<Vec<i64>>> {
     input.lines().map(|l| l.get_digits()).collect()
 }
@@ -33,15 +28,9 @@ fn find_next(nums: Vec<i64>) -> i64 {
     let mut down = 0;
     for i in (0..seqs.len() - 1).rev() {
         let right = seqs[i].first().unwrap();
-        dbg!(i);
-        dbg!(down);
-        dbg!(right);
         down = right - down;
-        dbg!(down);
     }
 
-    dbg!((&seqs, down));
-
     down
 }
 
@@ -0,0 +1,146 @@
+use std::collections::HashSet;
+
+use crate::{Direction4, Grid, Point};
+
+/// A beam entering `point` while travelling in `direction`
+pub type Ray = (Point, Direction4);
+
+/// Everything touched by the beams started by [`Grid::propagate`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Propagation {
+    /// every cell a beam passed through
+    pub energized: HashSet<Point>,
+    /// every cell together with each direction a beam entered it in
+    pub visited: HashSet<Ray>,
+}
+
+impl<T> Grid<T> {
+    /// Follows a beam from `start` until every branch leaves the grid or loops.
+    ///
+    /// `transition` gives the directions a beam leaves a tile in when it enters it going
+    /// in a direction. No directions absorbs the beam, several split it.
+    pub fn propagate<I>(&self, start: Ray, transition: impl Fn(&T, Direction4) -> I) -> Propagation
+    where
+        I: IntoIterator<Item = Direction4>,
+    {
+        let mut visited = HashSet::new();
+        let mut beams = Vec::new();
+        if self.validate(start.0.x, start.0.y).is_ok() {
+            beams.push(start);
+        }
+
+        while let Some((point, direction)) = beams.pop() {
+            if !visited.insert((point, direction)) {
+                continue;
+            }
+            let tile = &self.data[self.index(point.x, point.y)];
+            for out in transition(tile, direction) {
+                if let Some(next) = self.step(point, out) {
+                    beams.push((next, out));
+                }
+            }
+        }
+
+        Propagation {
+            energized: visited.iter().map(|&(point, _)| point).collect(),
+            visited,
+        }
+    }
+
+    /// Every ray that enters the grid from outside: down along the top row, up along the
+    /// bottom row, right along the left column and left along the right column.
+    ///
+    /// Corner cells appear once for each of their two edges.
+    pub fn entry_rays(&self) -> impl Iterator<Item = Ray> {
+        let (width, height) = (self.width, self.height);
+        let (right, bottom) = (width.saturating_sub(1), height.saturating_sub(1));
+        let columns = if height == 0 { 0 } else { width };
+        let rows = if width == 0 { 0 } else { height };
+
+        (0..columns)
+            .map(|x| (Point::new(x, 0), Direction4::Down))
+            .chain((0..columns).map(move |x| (Point::new(x, bottom), Direction4::Up)))
+            .chain((0..rows).map(|y| (Point::new(0, y), Direction4::Right)))
+            .chain((0..rows).map(move |y| (Point::new(right, y), Direction4::Left)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_grid;
+    use indoc::indoc;
+    use smallvec::{smallvec, SmallVec};
+
+    const EXAMPLE: &str = indoc! {r#"
+        .|...\....
+        |.-.\.....
+        .....|-...
+        ........|.
+        ..........
+        .........\
+        ..../.\\..
+        .-.-/..|..
+        .|....-|.\
+        ..//.|....
+    "#};
+
+    fn contraption(tile: &char, direction: Direction4) -> SmallVec<[Direction4; 2]> {
+        use Direction4::*;
+        match (tile, direction) {
+            ('/', Up) | ('\\', Down) => smallvec![Right],
+            ('/', Down) | ('\\', Up) => smallvec![Left],
+            ('/', Left) | ('\\', Right) => smallvec![Down],
+            ('/', Right) | ('\\', Left) => smallvec![Up],
+            ('-', Up | Down) => smallvec![Left, Right],
+            ('|', Left | Right) => smallvec![Up, Down],
+            _ => smallvec![direction],
+        }
+    }
+
+    #[test]
+    fn aoc2023_day16_example() {
+        let grid = parse_grid(EXAMPLE, |c| c).unwrap();
+
+        let beams = grid.propagate((Point::new(0, 0), Direction4::Right), contraption);
+        assert_eq!(beams.energized.len(), 46);
+        assert!(beams
+            .visited
+            .contains(&(Point::new(1, 0), Direction4::Right)));
+        assert!(beams
+            .visited
+            .contains(&(Point::new(1, 1), Direction4::Down)));
+
+        let best = grid
+            .entry_rays()
+            .map(|ray| grid.propagate(ray, contraption).energized.len())
+            .max();
+        assert_eq!(best, Some(51));
+    }
+
+    #[test]
+    fn propagate_absorbs_and_stops_outside() {
+        let grid = parse_grid("..#.\n", |c| c).unwrap();
+        let beams = grid.propagate((Point::new(0, 0), Direction4::Right), |tile, direction| {
+            (*tile != '#').then_some(direction)
+        });
+        assert_eq!(beams.energized.len(), 3);
+        assert!(!beams.energized.contains(&Point::new(3, 0)));
+
+        let outside = grid.propagate((Point::new(4, 0), Direction4::Left), |_, d| Some(d));
+        assert!(outside.visited.is_empty());
+    }
+
+    #[test]
+    fn entry_rays() {
+        let grid = parse_grid("abc\ndef\n", |c| c).unwrap();
+        let rays = grid.entry_rays().collect::<Vec<_>>();
+        assert_eq!(rays.len(), 2 * 3 + 2 * 2);
+        assert!(rays.contains(&(Point::new(2, 1), Direction4::Up)));
+        assert!(rays.contains(&(Point::new(2, 1), Direction4::Left)));
+        assert!(rays.contains(&(Point::new(0, 0), Direction4::Right)));
+        assert!(rays
+            .iter()
+            .all(|(point, direction)| grid.step(*point, direction.reverse()).is_none()));
+    }
+}
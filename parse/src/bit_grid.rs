@@ -0,0 +1,384 @@
+use std::{
+    fmt::Debug,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign},
+};
+
+use crate::{Direction4, Grid};
+
+const BITS: usize = u64::BITS as usize;
+
+/// A grid of booleans with one bit per cell, for moving whole sets of cells at once.
+///
+/// Each row starts on a fresh word, and the bits past `width` in a row's last word are
+/// always zero.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    words: Vec<u64>,
+    /// words per row
+    stride: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl BitGrid {
+    /// every cell unset
+    #[must_use]
+    pub fn new(width: usize, height: usize) -> Self {
+        let stride = width.div_ceil(BITS);
+        BitGrid {
+            words: vec![0; stride * height],
+            stride,
+            width,
+            height,
+        }
+    }
+
+    #[must_use]
+    pub fn from_fn(width: usize, height: usize, f: impl Fn(usize, usize) -> bool) -> Self {
+        let mut grid = BitGrid::new(width, height);
+        for y in 0..height {
+            for x in 0..width {
+                if f(x, y) {
+                    grid.set(x, y, true);
+                }
+            }
+        }
+        grid
+    }
+
+    fn position(&self, x: usize, y: usize) -> (usize, u64) {
+        assert!(
+            x < self.width && y < self.height,
+            "({x}, {y}) is outside the {}x{} grid",
+            self.width,
+            self.height
+        );
+        (y * self.stride + x / BITS, 1 << (x % BITS))
+    }
+
+    /// the bits of the last word in each row that are inside the grid
+    fn last_word_mask(&self) -> u64 {
+        match self.width % BITS {
+            0 => u64::MAX,
+            used => (1 << used) - 1,
+        }
+    }
+
+    /// Panics if `(x, y)` is outside the grid.
+    #[must_use]
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        let (i, bit) = self.position(x, y);
+        self.words[i] & bit != 0
+    }
+
+    /// Panics if `(x, y)` is outside the grid.
+    pub fn set(&mut self, x: usize, y: usize, value: bool) {
+        let (i, bit) = self.position(x, y);
+        if value {
+            self.words[i] |= bit;
+        } else {
+            self.words[i] &= !bit;
+        }
+    }
+
+    /// number of set cells
+    #[must_use]
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    /// the set cells in reading order
+    pub fn iter_ones(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.words.iter().enumerate().flat_map(move |(i, &word)| {
+            let (y, x0) = (i / self.stride, i % self.stride * BITS);
+            let mut rest = word;
+            std::iter::from_fn(move || {
+                (rest != 0).then(|| {
+                    let bit = rest.trailing_zeros() as usize;
+                    rest &= rest - 1;
+                    (x0 + bit, y)
+                })
+            })
+        })
+    }
+
+    /// Every set cell moved one step in `direction`, dropping the ones that leave the grid.
+    #[must_use]
+    pub fn shift(&self, direction: Direction4) -> BitGrid {
+        let mut result = BitGrid::new(self.width, self.height);
+        let stride = self.stride;
+        if stride == 0 || self.height == 0 {
+            return result;
+        }
+
+        match direction {
+            Direction4::Up => {
+                result.words[..(self.height - 1) * stride].copy_from_slice(&self.words[stride..]);
+            }
+            Direction4::Down => {
+                result.words[stride..].copy_from_slice(&self.words[..(self.height - 1) * stride]);
+            }
+            Direction4::Right => {
+                let mask = self.last_word_mask();
+                for (row, out) in self
+                    .words
+                    .chunks_exact(stride)
+                    .zip(result.words.chunks_exact_mut(stride))
+                {
+                    let mut carry = 0;
+                    for (word, out) in row.iter().zip(out.iter_mut()) {
+                        *out = (word << 1) | carry;
+                        carry = word >> (BITS - 1);
+                    }
+                    out[stride - 1] &= mask;
+                }
+            }
+            Direction4::Left => {
+                for (row, out) in self
+                    .words
+                    .chunks_exact(stride)
+                    .zip(result.words.chunks_exact_mut(stride))
+                {
+                    let mut carry = 0;
+                    for (word, out) in row.iter().zip(out.iter_mut()).rev() {
+                        *out = (word >> 1) | carry;
+                        carry = word << (BITS - 1);
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// Every cell one orthogonal step away from a set cell, the next frontier of a
+    /// breadth first search before it is masked to the open cells.
+    #[must_use]
+    pub fn neighbors(&self) -> BitGrid {
+        let mut result = self.shift(Direction4::Up);
+        for direction in [Direction4::Down, Direction4::Left, Direction4::Right] {
+            result |= &self.shift(direction);
+        }
+        result
+    }
+
+    /// cells set in `self` but not in `other`
+    #[must_use]
+    pub fn and_not(&self, other: &BitGrid) -> BitGrid {
+        self.zip_words(other, |a, b| a & !b)
+    }
+
+    fn zip_words(&self, other: &BitGrid, op: impl Fn(u64, u64) -> u64) -> BitGrid {
+        self.assert_same_size(other);
+        BitGrid {
+            words: self
+                .words
+                .iter()
+                .zip(&other.words)
+                .map(|(&a, &b)| op(a, b))
+                .collect(),
+            ..*self
+        }
+    }
+
+    fn assert_same_size(&self, other: &BitGrid) {
+        assert_eq!(
+            (self.width, self.height),
+            (other.width, other.height),
+            "bit grids must be the same size"
+        );
+    }
+
+    #[must_use]
+    pub fn to_grid(&self) -> Grid<bool> {
+        Grid {
+            data: (0..self.height)
+                .flat_map(|y| (0..self.width).map(move |x| self.get(x, y)))
+                .collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl BitAnd for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitand(self, rhs: Self) -> BitGrid {
+        self.zip_words(rhs, |a, b| a & b)
+    }
+}
+
+impl BitOr for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitor(self, rhs: Self) -> BitGrid {
+        self.zip_words(rhs, |a, b| a | b)
+    }
+}
+
+impl BitAndAssign<&BitGrid> for BitGrid {
+    fn bitand_assign(&mut self, rhs: &BitGrid) {
+        self.assert_same_size(rhs);
+        for (a, b) in self.words.iter_mut().zip(&rhs.words) {
+            *a &= b;
+        }
+    }
+}
+
+impl BitOrAssign<&BitGrid> for BitGrid {
+    fn bitor_assign(&mut self, rhs: &BitGrid) {
+        self.assert_same_size(rhs);
+        for (a, b) in self.words.iter_mut().zip(&rhs.words) {
+            *a |= b;
+        }
+    }
+}
+
+impl From<&Grid<bool>> for BitGrid {
+    fn from(grid: &Grid<bool>) -> Self {
+        grid.to_bit_grid(|&cell| cell)
+    }
+}
+
+impl From<&BitGrid> for Grid<bool> {
+    fn from(grid: &BitGrid) -> Self {
+        grid.to_grid()
+    }
+}
+
+impl<T> Grid<T> {
+    /// the cells where `predicate` holds
+    pub fn to_bit_grid(&self, predicate: impl Fn(&T) -> bool) -> BitGrid {
+        BitGrid::from_fn(self.width, self.height, |x, y| {
+            predicate(&self.data[self.index(x, y)])
+        })
+    }
+}
+
+impl Debug for BitGrid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "width={}, height={} {{", self.width, self.height)?;
+        for y in 0..self.height {
+            let row = (0..self.width)
+                .map(|x| if self.get(x, y) { '#' } else { '.' })
+                .collect::<String>();
+            writeln!(f, " {y}\t| {row}")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::parse_grid;
+    use indoc::indoc;
+
+    #[test]
+    fn aoc2023_day21_example() {
+        let grid = parse_grid(
+            indoc! {r#"
+                ...........
+                .....###.#.
+                .###.##..#.
+                ..#.#...#..
+                ....#.#....
+                .##..S####.
+                .##..#...#.
+                .......##..
+                .##.#.####.
+                .##..##.##.
+                ...........
+            "#},
+            |c| c,
+        )
+        .unwrap();
+        let open = grid.to_bit_grid(|&c| c != '#');
+        let mut reached = grid.to_bit_grid(|&c| c == 'S');
+        for _ in 0..6 {
+            reached = &reached.neighbors() & &open;
+        }
+        assert_eq!(reached.count_ones(), 16);
+    }
+
+    /// the same cells moved with a `HashSet`, for comparison
+    fn shifted_set(
+        cells: &HashSet<(usize, usize)>,
+        direction: Direction4,
+        size: (usize, usize),
+    ) -> HashSet<(usize, usize)> {
+        let delta = direction.delta();
+        cells
+            .iter()
+            .filter_map(|&(x, y)| {
+                let (x, y) = (
+                    x.checked_add_signed(delta.x)?,
+                    y.checked_add_signed(delta.y)?,
+                );
+                (x < size.0 && y < size.1).then_some((x, y))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn shifts_cross_word_boundaries() {
+        for width in [1, 63, 64, 65, 130] {
+            let size = (width, 3);
+            let grid = BitGrid::from_fn(width, 3, |x, y| (x * 7 + y * 3) % 5 < 2 || x + 1 == width);
+            let cells = grid.iter_ones().collect::<HashSet<_>>();
+            assert_eq!(cells.len(), grid.count_ones());
+
+            for direction in Direction4::iter() {
+                let shifted = grid.shift(direction);
+                assert_eq!(
+                    shifted.iter_ones().collect::<HashSet<_>>(),
+                    shifted_set(&cells, direction, size),
+                    "width {width} {direction:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = BitGrid::from_fn(70, 2, |x, _| x % 2 == 0);
+        let b = BitGrid::from_fn(70, 2, |x, _| x % 3 == 0);
+        assert_eq!((&a & &b).count_ones(), 2 * 12);
+        assert_eq!((&a | &b).count_ones(), 2 * (35 + 24 - 12));
+        assert_eq!(a.and_not(&b).count_ones(), 2 * (35 - 12));
+
+        let mut c = a.clone();
+        c &= &b;
+        assert_eq!(c, &a & &b);
+        c |= &a;
+        assert_eq!(c, a);
+        assert!(BitGrid::new(70, 2).is_empty());
+    }
+
+    #[test]
+    fn grid_round_trip() {
+        let grid = parse_grid("#..#\n.##.\n", |c| c == '#').unwrap();
+        let bits = BitGrid::from(&grid);
+        assert!(bits.get(3, 0));
+        assert!(!bits.get(0, 1));
+        assert_eq!(
+            bits.iter_ones().collect::<Vec<_>>(),
+            vec![(0, 0), (3, 0), (1, 1), (2, 1)]
+        );
+        assert_eq!(Grid::from(&bits), grid);
+        assert_eq!(
+            format!("{bits:?}"),
+            "width=4, height=2 {\n 0\t| #..#\n 1\t| .##.\n}"
+        );
+    }
+}
@@ -0,0 +1,291 @@
+use std::collections::HashSet;
+
+use crate::{Grid, Relationship};
+
+/// One connected region found by [`Grid::label_components`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Region {
+    pub size: usize,
+    /// inclusive bounding box
+    pub min: (usize, usize),
+    pub max: (usize, usize),
+    /// whether any cell is on the outer edge of the grid, so the region is not enclosed
+    pub touches_border: bool,
+}
+
+/// Every cell labelled with the id of its region, an index into `regions`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Components {
+    pub labels: Grid<usize>,
+    pub regions: Vec<Region>,
+}
+
+impl Components {
+    /// ids of the regions that do not reach the border of the grid
+    pub fn enclosed(&self) -> impl Iterator<Item = usize> + '_ {
+        self.regions
+            .iter()
+            .enumerate()
+            .filter(|(_, region)| !region.touches_border)
+            .map(|(id, _)| id)
+    }
+}
+
+/// neighbors of `(x, y)` inside a `width` by `height` grid
+fn neighbors_within(
+    (x, y): (usize, usize),
+    (width, height): (usize, usize),
+    relationship: &Relationship,
+) -> impl Iterator<Item = (usize, usize)> {
+    relationship.offsets().iter().filter_map(move |&(dx, dy)| {
+        let (x, y) = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        (x < width && y < height).then_some((x, y))
+    })
+}
+
+impl<T> Grid<T> {
+    fn flood(
+        &self,
+        starts: impl IntoIterator<Item = (usize, usize)>,
+        relationship: &Relationship,
+        passable: impl Fn(&T) -> bool,
+    ) -> HashSet<(usize, usize)> {
+        let size = (self.width, self.height);
+        let is_passable = |(x, y): (usize, usize)| {
+            x < self.width && y < self.height && passable(&self.data[self.index(x, y)])
+        };
+
+        let mut visited = HashSet::new();
+        let mut stack = starts
+            .into_iter()
+            .filter(|&coord| is_passable(coord))
+            .collect::<Vec<_>>();
+        visited.extend(stack.iter().copied());
+        while let Some(coord) = stack.pop() {
+            for neighbor in neighbors_within(coord, size, relationship) {
+                if is_passable(neighbor) && visited.insert(neighbor) {
+                    stack.push(neighbor);
+                }
+            }
+        }
+        visited
+    }
+
+    /// Every cell reachable from `start` by stepping between `passable` cells.
+    ///
+    /// Empty if `start` is itself not passable or outside the grid.
+    pub fn flood_fill(
+        &self,
+        start: (usize, usize),
+        relationship: &Relationship,
+        passable: impl Fn(&T) -> bool,
+    ) -> HashSet<(usize, usize)> {
+        self.flood([start], relationship, passable)
+    }
+
+    /// Every cell reachable from outside the grid, entering through any passable cell on
+    /// the border. The passable cells left out are enclosed.
+    pub fn flood_fill_from_border(
+        &self,
+        relationship: &Relationship,
+        passable: impl Fn(&T) -> bool,
+    ) -> HashSet<(usize, usize)> {
+        let (width, height) = (self.width, self.height);
+        let border = (0..width)
+            .flat_map(|x| [(x, 0), (x, height.saturating_sub(1))])
+            .chain((0..height).flat_map(|y| [(0, y), (width.saturating_sub(1), y)]));
+        self.flood(border, relationship, passable)
+    }
+
+    /// Splits the grid into connected regions, where neighboring cells are in the same
+    /// region when `same_region` holds for them.
+    ///
+    /// Region ids are assigned in reading order of each region's first cell.
+    pub fn label_components(
+        &self,
+        relationship: &Relationship,
+        same_region: impl Fn(&T, &T) -> bool,
+    ) -> Components {
+        let size = (self.width, self.height);
+        let mut labels: Grid<Option<usize>> = Grid {
+            data: vec![None; self.data.len()],
+            width: self.width,
+            height: self.height,
+        };
+        let mut regions = Vec::new();
+
+        for i in 0..self.data.len() {
+            if labels.data[i].is_some() {
+                continue;
+            }
+            let id = regions.len();
+            let start = self.reverse_index(i);
+            let mut region = Region {
+                size: 0,
+                min: start,
+                max: start,
+                touches_border: false,
+            };
+
+            labels.data[i] = Some(id);
+            let mut stack = vec![start];
+            while let Some((x, y)) = stack.pop() {
+                region.size += 1;
+                region.min = (region.min.0.min(x), region.min.1.min(y));
+                region.max = (region.max.0.max(x), region.max.1.max(y));
+                region.touches_border |=
+                    x == 0 || y == 0 || x + 1 == self.width || y + 1 == self.height;
+
+                let tile = &self.data[self.index(x, y)];
+                for (nx, ny) in neighbors_within((x, y), size, relationship) {
+                    let n = self.index(nx, ny);
+                    if labels.data[n].is_none() && same_region(tile, &self.data[n]) {
+                        labels.data[n] = Some(id);
+                        stack.push((nx, ny));
+                    }
+                }
+            }
+            regions.push(region);
+        }
+
+        Components {
+            labels: Grid {
+                data: labels
+                    .data
+                    .into_iter()
+                    .map(|id| id.expect("every cell is labelled"))
+                    .collect(),
+                width: self.width,
+                height: self.height,
+            },
+            regions,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_grid;
+    use indoc::indoc;
+
+    #[test]
+    fn flood_fill() {
+        let grid = parse_grid(
+            indoc! {r#"
+                ..#..
+                ..#..
+                ###..
+                ....#
+            "#},
+            |c| c,
+        )
+        .unwrap();
+        let open = |c: &char| *c == '.';
+
+        let corner = grid.flood_fill((0, 0), &Relationship::Orthogonal, open);
+        assert_eq!(corner.len(), 4);
+        assert!(corner.contains(&(1, 1)));
+
+        let rest = grid.flood_fill((4, 0), &Relationship::Orthogonal, open);
+        assert_eq!(rest.len(), 10);
+        assert!(rest.contains(&(0, 3)));
+
+        assert!(grid
+            .flood_fill((2, 0), &Relationship::Orthogonal, open)
+            .is_empty());
+        assert!(grid
+            .flood_fill((9, 9), &Relationship::Orthogonal, open)
+            .is_empty());
+
+        // diagonal steps still cannot cross the wall
+        let diagonal = grid.flood_fill((1, 1), &Relationship::Adjacent, open);
+        assert_eq!(diagonal.len(), 4);
+    }
+
+    #[test]
+    fn flood_fill_from_border_finds_enclosed() {
+        let grid = parse_grid(
+            indoc! {r#"
+                .....
+                .###.
+                .#.#.
+                .###.
+                .....
+            "#},
+            |c| c,
+        )
+        .unwrap();
+        let open = |c: &char| *c == '.';
+
+        let outside = grid.flood_fill_from_border(&Relationship::Orthogonal, open);
+        assert_eq!(outside.len(), 16);
+        assert!(!outside.contains(&(2, 2)));
+    }
+
+    #[test]
+    fn label_components() {
+        let grid = parse_grid(
+            indoc! {r#"
+                aab
+                cab
+                ccb
+                dbd
+            "#},
+            |c| c,
+        )
+        .unwrap();
+
+        let components = grid.label_components(&Relationship::Orthogonal, |a, b| a == b);
+        assert_eq!(
+            components.labels.data,
+            vec![
+                0, 0, 1, //
+                2, 0, 1, //
+                2, 2, 1, //
+                3, 4, 5, //
+            ]
+        );
+        assert_eq!(components.regions.len(), 6);
+        assert_eq!(
+            components.regions[0],
+            Region {
+                size: 3,
+                min: (0, 0),
+                max: (1, 1),
+                touches_border: true,
+            }
+        );
+        assert_eq!(components.regions[1].size, 3);
+        assert_eq!(components.regions[4].min, (1, 3));
+        assert_eq!(components.enclosed().count(), 0);
+
+        // the two b regions join diagonally
+        let adjacent = grid.label_components(&Relationship::Adjacent, |a, b| a == b);
+        assert_eq!(adjacent.regions.len(), 5);
+        assert_eq!(
+            adjacent.labels.get(1, 3).unwrap(),
+            adjacent.labels.get(2, 2).unwrap()
+        );
+    }
+
+    #[test]
+    fn label_components_enclosed() {
+        let grid = parse_grid(
+            indoc! {r#"
+                #####
+                #..##
+                #####
+                ....#
+            "#},
+            |c| c,
+        )
+        .unwrap();
+
+        let components = grid.label_components(&Relationship::Orthogonal, |a, b| a == b);
+        let enclosed = components.enclosed().collect::<Vec<_>>();
+        assert_eq!(enclosed.len(), 1);
+        let region = components.regions[enclosed[0]];
+        assert_eq!((region.size, region.min, region.max), (2, (1, 1), (2, 1)));
+    }
+}
@@ -0,0 +1,274 @@
+use std::{collections::HashMap, hash::Hash};
+
+use petgraph::{
+    graph::NodeIndex,
+    graphmap::GraphMap,
+    Directed,
+    Direction::{Incoming, Outgoing},
+    EdgeType, Graph,
+};
+
+use crate::{Grid, Relationship};
+
+/// A run of cells with no branches between two junctions
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Corridor {
+    /// steps from one junction to the other
+    pub length: u32,
+    /// the cells strictly between the two junctions, in walking order
+    pub cells: Vec<(usize, usize)>,
+}
+
+/// A grid graph with every corridor collapsed into a single weighted edge.
+///
+/// Nodes are the junctions, weighted by their coordinate in the original graph. Edges
+/// point the way the corridor can be walked, so a two way corridor is a pair of edges.
+#[derive(Debug, Clone)]
+pub struct JunctionGraph {
+    pub graph: Graph<(usize, usize), Corridor, Directed>,
+    index: HashMap<(usize, usize), NodeIndex>,
+}
+
+impl JunctionGraph {
+    #[must_use]
+    pub fn node(&self, coord: (usize, usize)) -> Option<NodeIndex> {
+        self.index.get(&coord).copied()
+    }
+
+    #[must_use]
+    pub fn coord(&self, node: NodeIndex) -> (usize, usize) {
+        self.graph[node]
+    }
+}
+
+/// neighbors regardless of edge direction, without repeats
+fn undirected_neighbors<N, E, Ty>(graph: &GraphMap<N, E, Ty>, node: N) -> Vec<N>
+where
+    N: Copy + Ord + Hash,
+    Ty: EdgeType,
+{
+    let mut neighbors = graph
+        .neighbors_directed(node, Outgoing)
+        .chain(graph.neighbors_directed(node, Incoming))
+        .filter(|&n| n != node)
+        .collect::<Vec<_>>();
+    neighbors.sort_unstable();
+    neighbors.dedup();
+    neighbors
+}
+
+/// Collapses every corridor of `graph` into one edge between junctions.
+///
+/// Junctions are the nodes in `keep`, usually the start and the goal, and every node that
+/// does not have exactly two neighbors, except isolated ones. A corridor is only walked
+/// along the direction of its edges, so one way cells like slopes stay one way.
+pub fn contract_corridors<E, Ty>(
+    graph: &GraphMap<(usize, usize), E, Ty>,
+    keep: impl IntoIterator<Item = (usize, usize)>,
+) -> JunctionGraph
+where
+    Ty: EdgeType,
+{
+    let mut junctions = keep
+        .into_iter()
+        .filter(|&coord| graph.contains_node(coord))
+        .collect::<Vec<_>>();
+    junctions.extend(graph.nodes().filter(|&node| {
+        let degree = undirected_neighbors(graph, node).len();
+        degree != 0 && degree != 2
+    }));
+    junctions.sort_unstable_by_key(|&(x, y)| (y, x));
+    junctions.dedup();
+
+    let mut result = JunctionGraph {
+        graph: Graph::new(),
+        index: HashMap::new(),
+    };
+    for &junction in &junctions {
+        let node = result.graph.add_node(junction);
+        result.index.insert(junction, node);
+    }
+
+    for &junction in &junctions {
+        for first in graph.neighbors_directed(junction, Outgoing) {
+            let (mut previous, mut current) = (junction, first);
+            let mut cells = Vec::new();
+            let end = loop {
+                if let Some(&end) = result.index.get(&current) {
+                    break Some(end);
+                }
+                cells.push(current);
+                let next = undirected_neighbors(graph, current)
+                    .into_iter()
+                    .find(|&n| n != previous)
+                    .expect("corridor cells have two neighbors");
+                if !graph.contains_edge(current, next) {
+                    break None;
+                }
+                (previous, current) = (current, next);
+            };
+
+            if let Some(end) = end.filter(|&end| end != result.index[&junction]) {
+                let corridor = Corridor {
+                    length: cells.len() as u32 + 1,
+                    cells,
+                };
+                result
+                    .graph
+                    .add_edge(result.index[&junction], end, corridor);
+            }
+        }
+    }
+    result
+}
+
+impl<T> Grid<T> {
+    /// [`contract_corridors`] of the graph where neighboring `passable` cells are
+    /// connected both ways.
+    pub fn contract_corridors(
+        &self,
+        relationship: &Relationship,
+        keep: impl IntoIterator<Item = (usize, usize)>,
+        passable: impl Fn(&T) -> bool,
+    ) -> JunctionGraph {
+        let mut graph = GraphMap::<(usize, usize), (), Directed>::new();
+        for ((x, y), tile) in self.iter() {
+            if !passable(tile) {
+                continue;
+            }
+            graph.add_node((x, y));
+            let neighbors = self.get_neighbors(x, y).expect("valid index");
+            for neighbor in neighbors.iter(relationship) {
+                if passable(self.get_tuple(neighbor).expect("valid index")) {
+                    graph.add_edge((x, y), neighbor, ());
+                }
+            }
+        }
+        contract_corridors(&graph, keep)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_grid, search::longest_path};
+    use indoc::indoc;
+    use petgraph::{algo::dijkstra, visit::EdgeRef};
+
+    const EXAMPLE: &str = indoc! {r#"
+        #.#####################
+        #.......#########...###
+        #######.#########.#.###
+        ###.....#.>.>.###.#.###
+        ###v#####.#v#.###.#.###
+        ###.>...#.#.#.....#...#
+        ###v###.#.#.#########.#
+        ###...#.#.#.......#...#
+        #####.#.#.#######.#.###
+        #.....#.#.#.......#...#
+        #.#####.#.#.#########v#
+        #.#...#...#...###...>.#
+        #.#.#v#######v###.###v#
+        #...#.>.#...>.>.#.###.#
+        #####v#.#.###v#.#.###.#
+        #.....#...#...#.#.#...#
+        #.#########.###.#.#.###
+        #...###...#...#...#.###
+        ###.###.#.###v#####v###
+        #...#...#.#.>.>.#.>.###
+        #.###.###.#.###.#.#v###
+        #.....###...###...#...#
+        #####################.#
+    "#};
+
+    const START: (usize, usize) = (1, 0);
+    const END: (usize, usize) = (21, 22);
+
+    /// the cell graph where slopes can only be left downhill
+    fn sloped_graph(grid: &Grid<char>) -> GraphMap<(usize, usize), (), Directed> {
+        grid.build_graph(
+            &Relationship::Orthogonal,
+            |(from, (fx, fy)), (to, (tx, ty))| {
+                let downhill = match from {
+                    '#' => return None,
+                    '>' => (fx + 1, fy),
+                    '<' => (fx.wrapping_sub(1), fy),
+                    'v' => (fx, fy + 1),
+                    '^' => (fx, fy.wrapping_sub(1)),
+                    _ => (tx, ty),
+                };
+                (to != '#' && downhill == (tx, ty)).then_some(())
+            },
+        )
+    }
+
+    fn cell_distance(graph: &GraphMap<(usize, usize), (), Directed>) -> Option<u32> {
+        dijkstra(graph, START, Some(END), |_| 1u32)
+            .get(&END)
+            .copied()
+    }
+
+    #[test]
+    fn aoc2023_day23_example() {
+        let grid = parse_grid(EXAMPLE, |c| c).unwrap();
+        let junctions =
+            grid.contract_corridors(&Relationship::Orthogonal, [START, END], |&c| c != '#');
+        assert_eq!(junctions.graph.node_count(), 9);
+        // every corridor can be walked both ways
+        assert_eq!(junctions.graph.edge_count(), 2 * 12);
+
+        let start = junctions.node(START).unwrap();
+        let first = junctions.graph.edges(start).next().unwrap();
+        assert_eq!(junctions.coord(first.target()), (3, 5));
+        assert_eq!(first.weight().length, 15);
+        assert_eq!(first.weight().cells.len(), 14);
+        assert_eq!(first.weight().cells[0], (1, 1));
+
+        let cells = sloped_graph(&grid);
+        let end = junctions.node(END).unwrap();
+        let contracted = dijkstra(&junctions.graph, start, Some(end), |e| e.weight().length);
+        assert_eq!(contracted.get(&end).copied(), cell_distance(&cells));
+    }
+
+    #[test]
+    fn slopes_stay_one_way() {
+        let grid = parse_grid(EXAMPLE, |c| c).unwrap();
+        let junctions = contract_corridors(&sloped_graph(&grid), [START, END]);
+        assert_eq!(junctions.graph.node_count(), 9);
+        // every junction in the example is entered and left through slopes
+        assert_eq!(junctions.graph.edge_count(), 12);
+        assert!(junctions
+            .graph
+            .neighbors_directed(junctions.node(START).unwrap(), Incoming)
+            .next()
+            .is_none());
+    }
+
+    #[test]
+    fn aoc2023_day23_longest_hikes() {
+        let grid = parse_grid(EXAMPLE, |c| c).unwrap();
+        let length = |junctions: &JunctionGraph| {
+            let (start, end) = (junctions.node(START).unwrap(), junctions.node(END).unwrap());
+            let (length, path) =
+                longest_path(&junctions.graph, start, end, |e| e.length.into()).unwrap();
+            assert_eq!(junctions.coord(path[path.len() - 1]), END);
+            length
+        };
+
+        assert_eq!(
+            length(&contract_corridors(&sloped_graph(&grid), [START, END])),
+            94
+        );
+        let flat = grid.contract_corridors(&Relationship::Orthogonal, [START, END], |&c| c != '#');
+        assert_eq!(length(&flat), 154);
+    }
+
+    #[test]
+    fn blocked_corridor_is_dropped() {
+        let grid = parse_grid("..>.<..\n", |c| c).unwrap();
+        let graph = sloped_graph(&grid);
+        let junctions = contract_corridors(&graph, [(0, 0), (6, 0)]);
+        assert_eq!(junctions.graph.node_count(), 2);
+        assert_eq!(junctions.graph.edge_count(), 0);
+    }
+}
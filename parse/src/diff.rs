@@ -0,0 +1,320 @@
+use std::{fmt::Debug, iter};
+
+use itertools::{EitherOrBoth, Itertools};
+
+use crate::{Grid, Overlay, Point, Rgb, Style};
+
+/// colour of changed cells in [`Diff::side_by_side`] and [`Diff::overlay`]
+const CHANGED: Rgb = Rgb::RED;
+
+/// A cell that differs between two grids, `None` where it is outside one of them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Change<'a, T> {
+    pub point: Point,
+    pub old: Option<&'a T>,
+    pub new: Option<&'a T>,
+}
+
+/// The cells that differ between two grids, built by [`Grid::diff`]
+pub struct Diff<'a, T> {
+    pub old: &'a Grid<T>,
+    pub new: &'a Grid<T>,
+    /// in reading order
+    pub changes: Vec<Change<'a, T>>,
+}
+
+impl<T> Grid<T> {
+    /// Every cell where `self` and `new` differ. Grids of different sizes are compared
+    /// over the larger of the two, with cells missing on one side counted as changes.
+    pub fn diff<'a>(&'a self, new: &'a Grid<T>) -> Diff<'a, T>
+    where
+        T: PartialEq,
+    {
+        let cell = |grid: &'a Grid<T>, x: usize, y: usize| {
+            (x < grid.width && y < grid.height).then(|| &grid.data[grid.index(x, y)])
+        };
+        let changes = (0..self.height.max(new.height))
+            .flat_map(|y| (0..self.width.max(new.width)).map(move |x| (x, y)))
+            .filter_map(|(x, y)| {
+                let (old, new) = (cell(self, x, y), cell(new, x, y));
+                (old != new).then_some(Change {
+                    point: Point::new(x, y),
+                    old,
+                    new,
+                })
+            })
+            .collect();
+
+        Diff {
+            old: self,
+            new,
+            changes,
+        }
+    }
+}
+
+impl<T> Diff<'_, T> {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.changes.iter().map(|change| change.point)
+    }
+
+    fn highlight(&self) -> Overlay {
+        Overlay::highlight(self.points(), CHANGED)
+    }
+
+    /// The new grid with changed cells highlighted, drawn as `*` when `colour` is off.
+    pub fn overlay(&self, style: impl Fn(&T) -> Style, colour: bool) -> String {
+        self.new
+            .render(style)
+            .overlay(self.highlight())
+            .colour(colour)
+            .to_string()
+    }
+
+    /// Both grids next to each other, old on the left. With `colour` the changed cells
+    /// are highlighted, otherwise rows with changes are marked with `<`.
+    pub fn side_by_side(&self, style: impl Fn(&T) -> Style, colour: bool) -> String {
+        // both sides number their rows in the same width so the grids start in one column
+        let margin = self
+            .old
+            .height
+            .max(self.new.height)
+            .saturating_sub(1)
+            .to_string()
+            .len();
+        let draw = |grid: &Grid<T>, colour: bool| {
+            let render = grid.render(&style).margin(margin).colour(colour);
+            if colour {
+                render.overlay(self.highlight()).to_string()
+            } else {
+                render.to_string()
+            }
+        };
+        let header = |grid: &Grid<T>| grid.width.saturating_sub(1).to_string().len();
+        let headers = header(self.old).max(header(self.new));
+        // the column numbers are as tall as the widest index, so the narrower grid gets
+        // blank lines on top to keep its rows level with the other side
+        let lines = |grid: &Grid<T>, colour: bool| {
+            let rendered = draw(grid, colour);
+            iter::repeat_n(String::new(), headers - header(grid))
+                .chain(rendered.lines().map(str::to_string))
+                .collect::<Vec<_>>()
+        };
+        let left = lines(self.old, colour);
+        let right = lines(self.new, colour);
+        // escape codes take no room on screen, so pad using the plain text
+        let plain_widths = lines(self.old, false)
+            .iter()
+            .map(|line| line.chars().count())
+            .collect::<Vec<_>>();
+        let width = plain_widths.iter().copied().max().unwrap_or(0);
+
+        let changed_rows = self.points().map(|point| point.y).collect::<Vec<_>>();
+
+        let mut result = format!("{:width$}   new\n", "old");
+        for (i, pair) in left.iter().zip_longest(&right).enumerate() {
+            let (left, right) = match pair {
+                EitherOrBoth::Both(left, right) => (left.as_str(), right.as_str()),
+                EitherOrBoth::Left(left) => (left.as_str(), ""),
+                EitherOrBoth::Right(right) => ("", right.as_str()),
+            };
+            let padding = width - plain_widths.get(i).copied().unwrap_or(0);
+            let marker = if !colour && i >= headers && changed_rows.contains(&(i - headers)) {
+                " <"
+            } else {
+                ""
+            };
+            result.push_str(&format!("{left}{:padding$}   {right}{marker}\n", ""));
+        }
+        result
+    }
+}
+
+impl<T: Debug> Debug for Diff<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let show = |cell: Option<&T>| cell.map_or("outside".to_string(), |t| format!("{t:?}"));
+        writeln!(f, "{} cells differ", self.changes.len())?;
+        for change in &self.changes {
+            writeln!(
+                f,
+                "  ({}, {}): {} -> {}",
+                change.point.x,
+                change.point.y,
+                show(change.old),
+                show(change.new)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Like `assert_eq!` for [`Grid`]s, but on failure shows both grids side by side and lists
+/// the changed cells. Cells are drawn with [`Style::from_debug`] unless a style is given.
+#[macro_export]
+macro_rules! assert_grid_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_grid_eq!($left, $right, $crate::Style::from_debug)
+    };
+    ($left:expr, $right:expr, $style:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                let diff = left.diff(right);
+                if !diff.is_empty() {
+                    panic!(
+                        "grids differ\n{}{:?}",
+                        diff.side_by_side($style, false),
+                        diff
+                    );
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_grid;
+    use indoc::indoc;
+
+    fn before() -> Grid<char> {
+        parse_grid(
+            indoc! {r#"
+                O....
+                O.#..
+                .O...
+            "#},
+            |c| c,
+        )
+        .unwrap()
+    }
+
+    fn after() -> Grid<char> {
+        parse_grid(
+            indoc! {r#"
+                O....
+                OO#..
+                .....
+            "#},
+            |c| c,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn diff() {
+        let (before, after) = (before(), after());
+        let diff = before.diff(&after);
+        assert_eq!(diff.len(), 2);
+        assert_eq!(
+            diff.changes[0],
+            Change {
+                point: Point::new(1, 1),
+                old: Some(&'.'),
+                new: Some(&'O'),
+            }
+        );
+        assert_eq!(
+            diff.points().collect::<Vec<_>>(),
+            vec![Point::new(1, 1), Point::new(1, 2)]
+        );
+        assert!(before.diff(&before).is_empty());
+        assert_eq!(
+            format!("{diff:?}"),
+            "2 cells differ\n  (1, 1): '.' -> 'O'\n  (1, 2): 'O' -> '.'\n"
+        );
+    }
+
+    #[test]
+    fn diff_different_sizes() {
+        let small = parse_grid("ab\n", |c| c).unwrap();
+        let large = parse_grid("ab\ncd\n", |c| c).unwrap();
+        let diff = small.diff(&large);
+        assert_eq!(diff.len(), 2);
+        assert_eq!(diff.changes[0].old, None);
+        assert_eq!(diff.changes[1].new, Some(&'d'));
+    }
+
+    #[test]
+    fn side_by_side() {
+        let (before, after) = (before(), after());
+        let rendered = before.diff(&after).side_by_side(Style::from_debug, false);
+        assert_eq!(
+            rendered,
+            indoc! {r#"
+                old         new
+                    01234       01234
+                0 | O....   0 | O....
+                1 | O.#..   1 | OO#.. <
+                2 | .O...   2 | ..... <
+            "#}
+        );
+
+        let overlay = before.diff(&after).overlay(|&c| c.into(), false);
+        assert_eq!(overlay, "    01234\n0 | O....\n1 | O*#..\n2 | .*...");
+
+        let coloured = before.diff(&after).side_by_side(|&c| c.into(), true);
+        assert!(coloured.contains("\x1b[48;2;255;0;0m"));
+        assert_eq!(coloured.lines().count(), 5);
+    }
+
+    #[test]
+    fn side_by_side_different_widths() {
+        let narrow = parse_grid("abcdefghi\nabcdefghi\n", |c| c).unwrap();
+        let wide = parse_grid("abcdefghijk\nabcdefghiXk\n", |c| c).unwrap();
+        let rendered = narrow.diff(&wide).side_by_side(Style::from_debug, false);
+        assert_eq!(
+            rendered,
+            indoc! {r#"
+                old             new
+                                              1
+                    012345678       01234567890
+                0 | abcdefghi   0 | abcdefghijk <
+                1 | abcdefghi   1 | abcdefghiXk <
+            "#}
+        );
+
+        let rendered = wide.diff(&narrow).side_by_side(Style::from_debug, false);
+        assert_eq!(
+            rendered.lines().nth(3),
+            Some("0 | abcdefghijk   0 | abcdefghi <")
+        );
+    }
+
+    #[test]
+    fn side_by_side_different_heights() {
+        let short = parse_grid(&"abc\n".repeat(10), |c| c).unwrap();
+        let tall = parse_grid(&"abc\n".repeat(11), |c| c).unwrap();
+        let rendered = short.diff(&tall).side_by_side(Style::from_debug, false);
+        let lines = rendered.lines().collect::<Vec<_>>();
+        assert_eq!(lines[1], "     012        012");
+        assert_eq!(lines[2], " 0 | abc    0 | abc");
+        assert_eq!(lines[11], " 9 | abc    9 | abc");
+        assert_eq!(lines[12], "           10 | abc <");
+
+        let rendered = tall.diff(&short).side_by_side(Style::from_debug, false);
+        assert_eq!(rendered.lines().nth(2), Some(" 0 | abc    0 | abc"));
+        assert_eq!(rendered.lines().nth(12), Some("10 | abc    <"));
+    }
+
+    #[test]
+    fn assert_grid_eq_passes() {
+        crate::assert_grid_eq!(before(), before());
+    }
+
+    #[test]
+    #[should_panic(expected = "1 | O.#..   1 | OO#.. <")]
+    fn assert_grid_eq_shows_both() {
+        crate::assert_grid_eq!(before(), after(), |&c| c.into());
+    }
+}
@@ -0,0 +1,240 @@
+use crate::Vec2;
+
+/// The four orthogonal directions, up is towards row 0
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction4 {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// The four orthogonal and four diagonal directions, up is towards row 0
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    Up,
+    Down,
+    Left,
+    Right,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+}
+
+impl Direction4 {
+    /// same order as [`crate::Relationship::Orthogonal`] neighbors
+    pub const ALL: [Direction4; 4] = [
+        Direction4::Up,
+        Direction4::Down,
+        Direction4::Left,
+        Direction4::Right,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Direction4> {
+        Self::ALL.into_iter()
+    }
+
+    #[must_use]
+    pub fn delta(self) -> Vec2 {
+        match self {
+            Direction4::Up => Vec2::new(0, -1),
+            Direction4::Down => Vec2::new(0, 1),
+            Direction4::Left => Vec2::new(-1, 0),
+            Direction4::Right => Vec2::new(1, 0),
+        }
+    }
+
+    /// 90 degrees counter-clockwise
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction4::Up => Direction4::Left,
+            Direction4::Left => Direction4::Down,
+            Direction4::Down => Direction4::Right,
+            Direction4::Right => Direction4::Up,
+        }
+    }
+
+    /// 90 degrees clockwise
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction4::Up => Direction4::Right,
+            Direction4::Right => Direction4::Down,
+            Direction4::Down => Direction4::Left,
+            Direction4::Left => Direction4::Up,
+        }
+    }
+
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        match self {
+            Direction4::Up => Direction4::Down,
+            Direction4::Down => Direction4::Up,
+            Direction4::Left => Direction4::Right,
+            Direction4::Right => Direction4::Left,
+        }
+    }
+
+    #[must_use]
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction4::Left | Direction4::Right)
+    }
+
+    #[must_use]
+    pub fn is_vertical(self) -> bool {
+        !self.is_horizontal()
+    }
+}
+
+impl Direction8 {
+    /// same order as [`crate::Relationship::Adjacent`] neighbors
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::Down,
+        Direction8::Left,
+        Direction8::Right,
+        Direction8::UpLeft,
+        Direction8::UpRight,
+        Direction8::DownLeft,
+        Direction8::DownRight,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Direction8> {
+        Self::ALL.into_iter()
+    }
+
+    #[must_use]
+    pub fn delta(self) -> Vec2 {
+        match self {
+            Direction8::Up => Vec2::new(0, -1),
+            Direction8::Down => Vec2::new(0, 1),
+            Direction8::Left => Vec2::new(-1, 0),
+            Direction8::Right => Vec2::new(1, 0),
+            Direction8::UpLeft => Vec2::new(-1, -1),
+            Direction8::UpRight => Vec2::new(1, -1),
+            Direction8::DownLeft => Vec2::new(-1, 1),
+            Direction8::DownRight => Vec2::new(1, 1),
+        }
+    }
+
+    /// 45 degrees counter-clockwise
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction8::Up => Direction8::UpLeft,
+            Direction8::UpLeft => Direction8::Left,
+            Direction8::Left => Direction8::DownLeft,
+            Direction8::DownLeft => Direction8::Down,
+            Direction8::Down => Direction8::DownRight,
+            Direction8::DownRight => Direction8::Right,
+            Direction8::Right => Direction8::UpRight,
+            Direction8::UpRight => Direction8::Up,
+        }
+    }
+
+    /// 45 degrees clockwise
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction8::Up => Direction8::UpRight,
+            Direction8::UpRight => Direction8::Right,
+            Direction8::Right => Direction8::DownRight,
+            Direction8::DownRight => Direction8::Down,
+            Direction8::Down => Direction8::DownLeft,
+            Direction8::DownLeft => Direction8::Left,
+            Direction8::Left => Direction8::UpLeft,
+            Direction8::UpLeft => Direction8::Up,
+        }
+    }
+
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        self.turn_right().turn_right().turn_right().turn_right()
+    }
+
+    #[must_use]
+    pub fn is_diagonal(self) -> bool {
+        Direction4::try_from(self).is_err()
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        match direction {
+            Direction4::Up => Direction8::Up,
+            Direction4::Down => Direction8::Down,
+            Direction4::Left => Direction8::Left,
+            Direction4::Right => Direction8::Right,
+        }
+    }
+}
+
+impl TryFrom<Direction8> for Direction4 {
+    type Error = Direction8;
+
+    /// fails with the original direction if it is diagonal
+    fn try_from(direction: Direction8) -> Result<Self, Self::Error> {
+        match direction {
+            Direction8::Up => Ok(Direction4::Up),
+            Direction8::Down => Ok(Direction4::Down),
+            Direction8::Left => Ok(Direction4::Left),
+            Direction8::Right => Ok(Direction4::Right),
+            diagonal => Err(diagonal),
+        }
+    }
+}
+
+impl From<Direction4> for Vec2 {
+    fn from(direction: Direction4) -> Self {
+        direction.delta()
+    }
+}
+
+impl From<Direction8> for Vec2 {
+    fn from(direction: Direction8) -> Self {
+        direction.delta()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn direction4_turns() {
+        for d in Direction4::iter() {
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.turn_left().turn_left(), d.reverse());
+            assert_eq!(d.turn_right().turn_right(), d.reverse());
+            assert_eq!(d.reverse().delta(), -d.delta());
+            assert_eq!(d.is_horizontal(), d.delta().y == 0);
+        }
+        assert_eq!(Direction4::Up.turn_right(), Direction4::Right);
+        assert_eq!(Direction4::Up.turn_left(), Direction4::Left);
+    }
+
+    #[test]
+    fn direction8_turns() {
+        for d in Direction8::iter() {
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.reverse().delta(), -d.delta());
+            assert_eq!(d.is_diagonal(), d.delta().manhattan() == 2);
+        }
+        assert_eq!(Direction8::Up.turn_right(), Direction8::UpRight);
+        assert_eq!(Direction8::UpLeft.reverse(), Direction8::DownRight);
+    }
+
+    #[test]
+    fn conversions() {
+        for d in Direction4::iter() {
+            assert_eq!(Direction4::try_from(Direction8::from(d)), Ok(d));
+            assert_eq!(Vec2::from(d), Direction8::from(d).delta());
+        }
+        assert_eq!(
+            Direction4::try_from(Direction8::UpLeft),
+            Err(Direction8::UpLeft)
+        );
+    }
+}
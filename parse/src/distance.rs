@@ -0,0 +1,174 @@
+use std::collections::VecDeque;
+
+use crate::{grid::Neighbors, Grid, Relationship};
+
+impl<T> Grid<T> {
+    fn breadth_first(
+        &self,
+        sources: impl IntoIterator<Item = (usize, usize)>,
+        relationship: &Relationship,
+        passable: impl Fn(&T) -> bool,
+        neighbors: impl Fn(usize, usize) -> Neighbors,
+    ) -> Grid<Option<u32>> {
+        let mut distances = Grid {
+            data: vec![None; self.data.len()],
+            width: self.width,
+            height: self.height,
+        };
+        let mut queue = VecDeque::new();
+        for (x, y) in sources {
+            if self.validate(x, y).is_ok() && passable(&self.data[self.index(x, y)]) {
+                let i = self.index(x, y);
+                if distances.data[i].is_none() {
+                    distances.data[i] = Some(0);
+                    queue.push_back((x, y));
+                }
+            }
+        }
+
+        while let Some((x, y)) = queue.pop_front() {
+            let distance = distances.data[self.index(x, y)].expect("queued cells have a distance");
+            for (nx, ny) in neighbors(x, y).iter(relationship) {
+                let i = self.index(nx, ny);
+                if distances.data[i].is_none() && passable(&self.data[i]) {
+                    distances.data[i] = Some(distance + 1);
+                    queue.push_back((nx, ny));
+                }
+            }
+        }
+        distances
+    }
+
+    /// The fewest steps from the nearest of `sources` to every cell, moving only between
+    /// `passable` cells. `None` for cells that cannot be reached.
+    ///
+    /// Sources that are outside the grid or not passable are ignored.
+    pub fn distance_field(
+        &self,
+        sources: impl IntoIterator<Item = (usize, usize)>,
+        relationship: &Relationship,
+        passable: impl Fn(&T) -> bool,
+    ) -> Grid<Option<u32>> {
+        self.breadth_first(sources, relationship, passable, |x, y| {
+            self.get_neighbors(x, y).expect("valid index")
+        })
+    }
+
+    /// Like [`Grid::distance_field`], but stepping off an edge comes back in on the
+    /// opposite edge.
+    pub fn distance_field_wrapping(
+        &self,
+        sources: impl IntoIterator<Item = (usize, usize)>,
+        relationship: &Relationship,
+        passable: impl Fn(&T) -> bool,
+    ) -> Grid<Option<u32>> {
+        self.breadth_first(sources, relationship, passable, |x, y| {
+            self.get_neighbors_wrapping(x, y).expect("valid index")
+        })
+    }
+}
+
+impl Grid<Option<u32>> {
+    /// Cells that can be reached in at most `steps` steps.
+    pub fn reachable_within(&self, steps: u32) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.iter()
+            .filter(move |(_, distance)| distance.is_some_and(|d| d <= steps))
+            .map(|(coord, _)| coord)
+    }
+
+    /// Cells that can be reached in exactly `steps` steps, when stepping back and forth is
+    /// allowed: those at most `steps` away with the same parity.
+    ///
+    /// Only exact when every step changes the parity of `x + y`, as for
+    /// [`Relationship::Orthogonal`] steps without wrapping.
+    pub fn reachable_in_exactly(&self, steps: u32) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.iter()
+            .filter(move |(_, distance)| distance.is_some_and(|d| d <= steps && d % 2 == steps % 2))
+            .map(|(coord, _)| coord)
+    }
+
+    /// The reachable cell with the largest distance, the first in reading order on ties.
+    #[must_use]
+    pub fn farthest(&self) -> Option<((usize, usize), u32)> {
+        self.iter()
+            .filter_map(|(coord, distance)| Some((coord, (*distance)?)))
+            .fold(None, |best, (coord, distance)| match best {
+                Some((_, best_distance)) if best_distance >= distance => best,
+                _ => Some((coord, distance)),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_grid;
+    use indoc::indoc;
+
+    const EXAMPLE: &str = indoc! {r#"
+        ...........
+        .....###.#.
+        .###.##..#.
+        ..#.#...#..
+        ....#.#....
+        .##..S####.
+        .##..#...#.
+        .......##..
+        .##.#.####.
+        .##..##.##.
+        ...........
+    "#};
+
+    #[test]
+    fn aoc2023_day21_example() {
+        let grid = parse_grid(EXAMPLE, |c| c).unwrap();
+        let start = grid.lookup('S');
+        let distances = grid.distance_field(start, &Relationship::Orthogonal, |&c| c != '#');
+
+        assert_eq!(distances.get(5, 5).unwrap(), &Some(0));
+        assert_eq!(distances.get(4, 4).unwrap(), &None);
+        assert_eq!(distances.reachable_in_exactly(1).count(), 2);
+        assert_eq!(distances.reachable_in_exactly(2).count(), 4);
+        assert_eq!(distances.reachable_in_exactly(6).count(), 16);
+        assert_eq!(distances.reachable_within(1).count(), 3);
+    }
+
+    #[test]
+    fn multiple_sources_and_farthest() {
+        let grid = parse_grid("a.#..\n.....\n....b\n", |c| c).unwrap();
+        let sources = [(0, 0), (4, 2), (2, 0)];
+        let distances = grid.distance_field(sources, &Relationship::Orthogonal, |&c| c != '#');
+
+        assert_eq!(distances.get(2, 0).unwrap(), &None);
+        assert_eq!(distances.get(1, 1).unwrap(), &Some(2));
+        assert_eq!(distances.get(3, 0).unwrap(), &Some(3));
+        assert_eq!(distances.farthest(), Some(((3, 0), 3)));
+
+        let diagonal = grid.distance_field([(0, 0)], &Relationship::Adjacent, |_| true);
+        assert_eq!(diagonal.get(4, 2).unwrap(), &Some(4));
+        assert_eq!(diagonal.farthest(), Some(((4, 0), 4)));
+    }
+
+    #[test]
+    fn wrapping() {
+        let grid = parse_grid(
+            indoc! {r#"
+                S.#..
+                ..#..
+                ..#..
+            "#},
+            |c| c,
+        )
+        .unwrap();
+        let open = |&c: &char| c != '#';
+
+        let flat = grid.distance_field([(0, 0)], &Relationship::Orthogonal, open);
+        assert_eq!(flat.get(4, 0).unwrap(), &None);
+
+        let wrapped = grid.distance_field_wrapping([(0, 0)], &Relationship::Orthogonal, open);
+        assert_eq!(wrapped.get(4, 0).unwrap(), &Some(1));
+        assert_eq!(wrapped.get(0, 2).unwrap(), &Some(1));
+        assert_eq!(wrapped.get(3, 1).unwrap(), &Some(3));
+        assert_eq!(wrapped.farthest(), Some(((3, 1), 3)));
+    }
+}
@@ -6,11 +6,13 @@ use std::{
 };
 
 use itertools::Itertools;
-use miette::{Diagnostic, Result};
+use miette::{Diagnostic, NamedSource, Result, SourceSpan};
 
 use petgraph::graphmap::GraphMap;
 use thiserror::Error;
 
+use crate::{Direction4, Point, Stencil, Symmetry, TiledGrid, Vec2};
+
 #[derive(Clone, PartialEq, Eq, Hash)]
 /// x is the column, y is the row
 pub struct Grid<T> {
@@ -70,6 +72,48 @@ pub enum GridError {
         height: usize,
         axis: Axis,
     },
+    #[diagnostic(
+        code(parse::grid::ragged_row),
+        help("every row of a grid must be the same width, check for trailing spaces")
+    )]
+    #[error("row {row} is {found} wide, but the first row is {expected} wide")]
+    RaggedRow {
+        #[source_code]
+        src: NamedSource,
+        #[label("{expected} wide")]
+        first: SourceSpan,
+        #[label("{found} wide, differs from column {column}")]
+        span: SourceSpan,
+        row: usize,
+        column: usize,
+        expected: usize,
+        found: usize,
+    },
+    #[diagnostic(
+        code(parse::grid::carriage_return),
+        help("convert the input to LF line endings")
+    )]
+    #[error("carriage return in row {row}")]
+    CarriageReturn {
+        #[source_code]
+        src: NamedSource,
+        #[label("\\r at column {column}")]
+        span: SourceSpan,
+        row: usize,
+        column: usize,
+    },
+    #[diagnostic(
+        code(parse::grid::trailing_blank),
+        help("remove the blank lines after the last row")
+    )]
+    #[error("blank lines after row {row}")]
+    TrailingBlank {
+        #[source_code]
+        src: NamedSource,
+        #[label("blank")]
+        span: SourceSpan,
+        row: usize,
+    },
 }
 
 #[derive(Debug)]
@@ -95,6 +139,31 @@ pub enum Relationship {
     Adjacent,
 }
 
+impl Relationship {
+    /// `(dx, dy)` offsets in the same order as [`Neighbors::iter`]
+    #[must_use]
+    pub fn offsets(&self) -> &'static [(isize, isize)] {
+        const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
+        const DIAGONAL: [(isize, isize); 4] = [(-1, -1), (1, -1), (-1, 1), (1, 1)];
+        const ADJACENT: [(isize, isize); 8] = [
+            (0, -1),
+            (0, 1),
+            (-1, 0),
+            (1, 0),
+            (-1, -1),
+            (1, -1),
+            (-1, 1),
+            (1, 1),
+        ];
+
+        match self {
+            Relationship::Orthogonal => &ORTHOGONAL,
+            Relationship::Diagonal => &DIAGONAL,
+            Relationship::Adjacent => &ADJACENT,
+        }
+    }
+}
+
 impl Neighbors {
     pub fn iter(&self, relation: &Relationship) -> impl Iterator<Item = (usize, usize)> {
         macro_rules! iter_chain {
@@ -189,6 +258,21 @@ impl<T> Grid<T> {
         self.get(x, y)
     }
 
+    pub fn get_at(&self, point: Point) -> Result<&T> {
+        self.get(point.x, point.y)
+    }
+
+    pub fn set_at(&mut self, point: Point, value: T) {
+        self.set(point.x, point.y, value);
+    }
+
+    /// the neighbor of `point` in `direction`, if it is inside the grid
+    pub fn step(&self, point: Point, direction: impl Into<Vec2>) -> Option<Point> {
+        let next = point.checked_add(direction)?;
+        self.validate(next.x, next.y).ok()?;
+        Some(next)
+    }
+
     #[must_use]
     pub fn build_lookup(&self) -> HashMap<T, Vec<(usize, usize)>>
     where
@@ -265,15 +349,19 @@ impl<T> Grid<T> {
         })
     }
 
+    /// Adds an edge from every cell to each of its in-bounds neighbors by `relation`
+    /// where `edge_map_fn` returns one. `relation` is a `&`[`Relationship`] or a [`Stencil`];
+    /// cells are visited in reading order and their neighbors in the stencil's order.
     pub fn build_graph<E, Ty>(
         &self,
-        relation: &Relationship,
+        relation: impl Into<Stencil>,
         edge_map_fn: impl Fn((T, (usize, usize)), (T, (usize, usize))) -> Option<E>,
     ) -> GraphMap<(usize, usize), E, Ty>
     where
         T: Eq + Hash + Copy + Ord,
         Ty: petgraph::EdgeType,
     {
+        let stencil: Stencil = relation.into();
         let mut graph = GraphMap::new();
         for y in 0..self.height {
             for x in 0..self.width {
@@ -282,8 +370,7 @@ impl<T> Grid<T> {
                 if !graph.contains_node(cord) {
                     graph.add_node(cord);
                 }
-                let neighbors = self.get_neighbors(x, y).expect("valid index");
-                for neighbor in neighbors.iter(relation) {
+                for neighbor in self.stencil_neighbors(x, y, &stencil) {
                     let neighbor_tile = self.get_tuple(neighbor).expect("valid index");
                     if let Some(edge) = edge_map_fn((*tile, cord), (*neighbor_tile, neighbor)) {
                         graph.add_edge(cord, neighbor, edge);
@@ -337,26 +424,66 @@ impl<T> Grid<T> {
         Ok(())
     }
 
+    /// Slides every movable cell as far as it goes towards `direction`, like rocks on a
+    /// tilted platform, in one pass over each row or column.
+    ///
+    /// Movable cells stop at the edge, at a blocker, or against a movable cell that has
+    /// already stopped, swapping places with the cells they pass over. Returns how many
+    /// cells moved, which is 0 once the grid has settled.
+    pub fn compact(
+        &mut self,
+        direction: Direction4,
+        is_movable: impl Fn(&T) -> bool,
+        is_blocker: impl Fn(&T) -> bool,
+    ) -> usize {
+        let width = self.width;
+        let (lanes, lane_len) = if direction.is_vertical() {
+            (self.width, self.height)
+        } else {
+            (self.height, self.width)
+        };
+        // the i-th cell of a lane, counting from the edge cells slide towards
+        let index = |lane: usize, i: usize| {
+            let i = match direction {
+                Direction4::Up | Direction4::Left => i,
+                Direction4::Down | Direction4::Right => lane_len - 1 - i,
+            };
+            if direction.is_vertical() {
+                i * width + lane
+            } else {
+                lane * width + i
+            }
+        };
+
+        let mut moved = 0;
+        for lane in 0..lanes {
+            let mut free = 0;
+            for i in 0..lane_len {
+                let cell = &self.data[index(lane, i)];
+                if is_blocker(cell) {
+                    free = i + 1;
+                } else if is_movable(cell) {
+                    if free != i {
+                        self.data.swap(index(lane, free), index(lane, i));
+                        moved += 1;
+                    }
+                    free += 1;
+                }
+            }
+        }
+        moved
+    }
+
     #[must_use]
     pub fn compute_columns(&self) -> Vec<Vec<&T>> {
-        (0..self.width)
-            .map(|x| {
-                (0..self.height)
-                    .map(|y| self.get(x, y).expect("valid index"))
-                    .collect()
-            })
+        self.columns()
+            .map(|column| column.iter().collect())
             .collect()
     }
 
     #[must_use]
     pub fn compute_rows(&self) -> Vec<Vec<&T>> {
-        (0..self.height)
-            .map(|y| {
-                (0..self.width)
-                    .map(|x| self.get(x, y).expect("valid index"))
-                    .collect()
-            })
-            .collect()
+        self.rows().map(|row| row.iter().collect()).collect()
     }
 
     pub fn set(&mut self, x: usize, y: usize, value: T) {
@@ -434,6 +561,147 @@ impl<T> Grid<T> {
         new
     }
 
+    /// The grid after `symmetry`, with each cell moved as in [`Symmetry::apply`].
+    #[must_use]
+    pub fn transform(&self, symmetry: Symmetry) -> Self
+    where
+        T: Clone,
+    {
+        let (width, height) = symmetry.dimensions((self.width, self.height));
+        let inverse = symmetry.inverse();
+        let mut data = Vec::with_capacity(self.data.len());
+        for y in 0..height {
+            for x in 0..width {
+                let (from_x, from_y) = inverse.apply((x, y), (width, height));
+                data.push(self.unchecked_get(from_x, from_y).clone());
+            }
+        }
+
+        Grid {
+            data,
+            width,
+            height,
+        }
+    }
+
+    /// swaps x and y, so rows become columns
+    #[must_use]
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.transform(Symmetry::Transpose)
+    }
+
+    /// reverses each row
+    #[must_use]
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.transform(Symmetry::FlipHorizontal)
+    }
+
+    /// reverses the order of the rows
+    #[must_use]
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.transform(Symmetry::FlipVertical)
+    }
+
+    /// all 8 symmetries of the grid, starting with the grid itself
+    pub fn symmetries(&self) -> impl Iterator<Item = (Symmetry, Self)> + '_
+    where
+        T: Clone,
+    {
+        Symmetry::iter().map(|symmetry| (symmetry, self.transform(symmetry)))
+    }
+
+    /// The smallest of the 8 symmetries, so grids that are rotations or reflections of
+    /// each other have the same canonical form.
+    #[must_use]
+    pub fn canonical(&self) -> Self
+    where
+        T: Clone + Ord,
+    {
+        self.symmetries()
+            .map(|(_, grid)| grid)
+            .min_by(|a, b| (a.width, a.height, &a.data).cmp(&(b.width, b.height, &b.data)))
+            .expect("there are always 8 symmetries")
+    }
+
+    /// Panics unless the grid is square.
+    pub fn transpose_in_place(&mut self) {
+        assert_eq!(
+            self.width, self.height,
+            "only square grids can be transposed in place"
+        );
+        for y in 0..self.height {
+            for x in y + 1..self.width {
+                let (a, b) = (self.index(x, y), self.index(y, x));
+                self.data.swap(a, b);
+            }
+        }
+    }
+
+    pub fn flip_horizontal_in_place(&mut self) {
+        if self.width == 0 {
+            return;
+        }
+        for row in self.data.chunks_mut(self.width) {
+            row.reverse();
+        }
+    }
+
+    pub fn flip_vertical_in_place(&mut self) {
+        let width = self.width;
+        for y in 0..self.height / 2 {
+            let mirrored = self.index(0, self.height - 1 - y);
+            let (top, bottom) = self.data.split_at_mut(mirrored);
+            top[y * width..(y + 1) * width].swap_with_slice(&mut bottom[..width]);
+        }
+    }
+
+    /// Panics unless the grid is square.
+    pub fn rotate_cw_in_place(&mut self) {
+        self.transform_in_place(Symmetry::RotateCw);
+    }
+
+    /// Panics unless the grid is square.
+    pub fn rotate_ccw_in_place(&mut self) {
+        self.transform_in_place(Symmetry::RotateCcw);
+    }
+
+    /// Applies `symmetry` without allocating.
+    ///
+    /// Panics if `symmetry` swaps the axes and the grid is not square.
+    pub fn transform_in_place(&mut self, symmetry: Symmetry) {
+        match symmetry {
+            Symmetry::Identity => {}
+            Symmetry::FlipHorizontal => self.flip_horizontal_in_place(),
+            Symmetry::FlipVertical => self.flip_vertical_in_place(),
+            Symmetry::Rotate180 => {
+                self.flip_horizontal_in_place();
+                self.flip_vertical_in_place();
+            }
+            Symmetry::Transpose => self.transpose_in_place(),
+            Symmetry::RotateCw => {
+                self.transpose_in_place();
+                self.flip_horizontal_in_place();
+            }
+            Symmetry::RotateCcw => {
+                self.transpose_in_place();
+                self.flip_vertical_in_place();
+            }
+            Symmetry::AntiTranspose => {
+                self.transpose_in_place();
+                self.transform_in_place(Symmetry::Rotate180);
+            }
+        }
+    }
+
     pub fn map<V>(&self, fun: impl Fn(((usize, usize), &T)) -> V) -> Grid<V> {
         Grid {
             data: self.iter().map(fun).collect_vec(),
@@ -442,6 +710,10 @@ impl<T> Grid<T> {
         }
     }
 
+    pub fn tiled(&self) -> TiledGrid<'_, T> {
+        TiledGrid::new(self)
+    }
+
     pub fn debug_to_file(&self, name: impl Display) -> Result<()>
     where
         T: Debug,
@@ -458,18 +730,77 @@ impl<T> Grid<T> {
     }
 }
 
-pub fn parse_grid<T>(input: &str, map_fn: impl Fn(char) -> T) -> Result<Grid<T>> {
-    let mut data = Vec::with_capacity(input.len());
-    let mut width = 0;
-    let mut height = 0;
-    for line in input.lines() {
-        height += 1;
-        width = 0;
-        for c in line.chars() {
-            width += 1;
-            data.push(map_fn(c));
+/// Checks that `input` is a rectangle of characters, returning its `(width, height)`.
+///
+/// A single trailing newline is allowed, but not CRLF line endings, rows of different
+/// widths or blank lines after the grid.
+pub fn grid_dimensions(input: &str) -> Result<(usize, usize), GridError> {
+    let src = || NamedSource::new("grid", input.to_string());
+    let row_at = |offset: usize| input[..offset].matches('\n').count();
+    let column_at = |offset: usize| {
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        input[line_start..offset].chars().count()
+    };
+
+    if let Some(offset) = input.find('\r') {
+        return Err(GridError::CarriageReturn {
+            src: src(),
+            span: (offset, 1).into(),
+            row: row_at(offset),
+            column: column_at(offset),
+        });
+    }
+
+    let body = input.strip_suffix('\n').unwrap_or(input);
+    // only blank lines would be a grid of empty rows, which is just an empty grid
+    if body.chars().all(|c| c == '\n') {
+        return Ok((0, 0));
+    }
+
+    let first = body.split('\n').next().unwrap_or_default();
+    let width = first.chars().count();
+    let mut offset = 0;
+    for (row, line) in body.split('\n').enumerate() {
+        let found = line.chars().count();
+        if found != width {
+            if body[offset..].trim_start_matches('\n').is_empty() {
+                // the newline ending the last row is not part of the blank
+                return Err(GridError::TrailingBlank {
+                    src: src(),
+                    span: (offset, input.len() - offset).into(),
+                    row: row - 1,
+                });
+            }
+
+            let column = width.min(found);
+            let span_start = offset
+                + line
+                    .char_indices()
+                    .nth(column)
+                    .map_or(line.len(), |(i, _)| i);
+            return Err(GridError::RaggedRow {
+                src: src(),
+                first: (0, first.len()).into(),
+                span: (span_start, offset + line.len() - span_start).into(),
+                row,
+                column,
+                expected: width,
+                found,
+            });
         }
+        offset += line.len() + 1;
     }
+
+    Ok((width, body.matches('\n').count() + 1))
+}
+
+pub fn parse_grid<T>(input: &str, map_fn: impl Fn(char) -> T) -> Result<Grid<T>> {
+    let (width, height) = grid_dimensions(input)?;
+    let data = input
+        .lines()
+        .flat_map(str::chars)
+        .map(map_fn)
+        .collect::<Vec<_>>();
     Ok(Grid {
         data,
         width,
@@ -513,19 +844,15 @@ macro_rules! Tile {
             #[track_caller]
             pub fn parse_grid(input: &str) -> Result<Grid<Tile>> {
                 use miette::WrapErr;
-                let mut data = Vec::with_capacity(input.len());
-                let mut width = 0;
-                let mut height = 0;
-                for line in input.lines() {
-                    height += 1;
-                    width = 0;
-                    for c in line.chars() {
-                        width += 1;
+                let (width, height) = $crate::grid_dimensions(input)?;
+                let mut data = Vec::with_capacity(width * height);
+                for (y, line) in input.lines().enumerate() {
+                    for (x, c) in line.chars().enumerate() {
                         data.push(Tile::try_from(c)
-                            .wrap_err(format!("y={y} \"{line}\"", y = height - 1, line = line))
+                            .wrap_err(format!("y={y} \"{line}\"", y = y, line = line))
                             .wrap_err(format!("'{c}' at ({x}, {y}) called from {line}",
-                                x = width - 1,
-                                y = height - 1,
+                                x = x,
+                                y = y,
                                 line = std::panic::Location::caller()
                             ))?
                         );
@@ -1131,15 +1458,228 @@ mod tests {
         assert_eq!(grid.rotate_cw().rotate_cw().rotate_cw(), grid.rotate_ccw());
     }
 
+    #[test]
+    fn grid_point_direction() {
+        use crate::{Direction4, Direction8};
+
+        let input = indoc! {r#"
+            abc
+            def
+        "#};
+        let mut grid = parse_grid(input, |c| c).unwrap();
+        let e = Point::new(1, 1);
+
+        assert_eq!(grid.get_at(e).unwrap(), &'e');
+        assert_eq!(grid.step(e, Direction4::Up), Some(Point::new(1, 0)));
+        assert_eq!(grid.step(e, Direction4::Down), None);
+        assert_eq!(grid.step(e, Direction8::UpRight), Some(Point::new(2, 0)));
+        assert_eq!(grid.step(Point::new(0, 0), Direction4::Left), None);
+        assert_eq!(grid.step(e, Vec2::new(-1, -1)), Some(Point::new(0, 0)));
+
+        let stepped = Direction4::iter()
+            .filter_map(|d| grid.step(e, d))
+            .collect_vec();
+        let neighbors = grid
+            .get_neighbors(1, 1)
+            .unwrap()
+            .iter(&Relationship::Orthogonal)
+            .map(Point::from)
+            .collect_vec();
+        assert_eq!(stepped, neighbors);
+
+        grid.set_at(e, 'x');
+        assert_eq!(grid.get(1, 1).unwrap(), &'x');
+    }
+
+    #[test]
+    fn grid_dimensions_accepts_rectangles() {
+        assert_eq!(grid_dimensions("abc\ndef\n").unwrap(), (3, 2));
+        assert_eq!(grid_dimensions("abc\ndef").unwrap(), (3, 2));
+        assert_eq!(grid_dimensions("äb\ncd").unwrap(), (2, 2));
+        assert_eq!(grid_dimensions("").unwrap(), (0, 0));
+        assert_eq!(grid_dimensions("\n").unwrap(), (0, 0));
+        assert_eq!(grid_dimensions("\n\n").unwrap(), (0, 0));
+        assert_eq!(grid_dimensions("\n\n\n").unwrap(), (0, 0));
+
+        let empty = parse_grid("\n\n", |c| c).unwrap();
+        assert_eq!((empty.width, empty.height), (0, 0));
+        assert!(empty.data.is_empty());
+        assert!(matches!(
+            grid_dimensions("\nabc\n"),
+            Err(GridError::RaggedRow { row: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn parse_grid_rejects_ragged_rows() {
+        let err = parse_grid("abc\nde\nfgh\n", |c| c).unwrap_err();
+        let Some(GridError::RaggedRow {
+            first,
+            span,
+            row,
+            column,
+            expected,
+            found,
+            ..
+        }) = err.downcast_ref::<GridError>()
+        else {
+            panic!("expected a ragged row, got {err:?}");
+        };
+        assert_eq!((*row, *column, *expected, *found), (1, 2, 3, 2));
+        assert_eq!((first.offset(), first.len()), (0, 3));
+        assert_eq!((span.offset(), span.len()), (6, 0));
+
+        // a trailing space is pointed at directly
+        let err = grid_dimensions("abc\ndef \nghi").unwrap_err();
+        let GridError::RaggedRow { span, row, .. } = err else {
+            panic!("expected a ragged row, got {err:?}");
+        };
+        assert_eq!((span.offset(), span.len(), row), (7, 1, 1));
+        assert_eq!(
+            err.to_string(),
+            "row 1 is 4 wide, but the first row is 3 wide"
+        );
+    }
+
+    #[test]
+    fn parse_grid_rejects_crlf_and_trailing_blank() {
+        let err = grid_dimensions("abc\r\ndef\r\n").unwrap_err();
+        let GridError::CarriageReturn {
+            span, row, column, ..
+        } = err
+        else {
+            panic!("expected a carriage return, got {err:?}");
+        };
+        assert_eq!((span.offset(), row, column), (3, 0, 3));
+
+        let err = grid_dimensions("abc\ndef\n\n\n").unwrap_err();
+        let GridError::TrailingBlank { span, row, .. } = err else {
+            panic!("expected a trailing blank, got {err:?}");
+        };
+        assert_eq!((span.offset(), span.len(), row), (8, 2, 1));
+
+        // a blank line inside the grid is a ragged row, not a trailing blank
+        assert!(matches!(
+            grid_dimensions("abc\n\ndef"),
+            Err(GridError::RaggedRow { row: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn tile_macro_rejects_ragged_rows() {
+        Tile! {
+            Rock = '#',
+            Ash = '.',
+        }
+
+        let err = Tile::parse_grid("##\n#.#\n").unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<GridError>(),
+            Some(GridError::RaggedRow {
+                row: 1,
+                found: 3,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn compact_rolls_rocks() {
+        Tile! {
+            Round = 'O',
+            Cube = '#',
+            Empty = '.',
+        }
+
+        let input = indoc! {r#"
+            O....#....
+            O.OO#....#
+            .....##...
+            OO.#O....O
+            .O.....O#.
+            O.#..O.#.#
+            ..O..#O..O
+            .......O..
+            #....###..
+            #OO..#....
+        "#};
+        let tilted = indoc! {r#"
+            OOOO.#.O..
+            OO..#....#
+            OO..O##..O
+            O..#.OO...
+            ........#.
+            ..#....#.#
+            ..O..#.O.O
+            ..O.......
+            #....###..
+            #....#....
+        "#};
+        let mut grid = Tile::parse_grid(input).unwrap();
+        let is_round = |t: &Tile| *t == Tile::Round;
+        let is_cube = |t: &Tile| *t == Tile::Cube;
+
+        assert!(grid.compact(Direction4::Up, is_round, is_cube) > 0);
+        assert_eq!(grid, Tile::parse_grid(tilted).unwrap());
+        assert_eq!(grid.compact(Direction4::Up, is_round, is_cube), 0);
+
+        let load = grid
+            .lookup(Tile::Round)
+            .iter()
+            .map(|(_, y)| grid.height - y)
+            .sum::<usize>();
+        assert_eq!(load, 136);
+
+        // one spin cycle
+        let mut grid = Tile::parse_grid(input).unwrap();
+        for direction in [
+            Direction4::Up,
+            Direction4::Left,
+            Direction4::Down,
+            Direction4::Right,
+        ] {
+            grid.compact(direction, is_round, is_cube);
+        }
+        let spun = indoc! {r#"
+            .....#....
+            ....#...O#
+            ...OO##...
+            .OO#......
+            .....OOO#.
+            .O#...O#.#
+            ....O#....
+            ......OOOO
+            #...O###..
+            #..OO#....
+        "#};
+        assert_eq!(grid, Tile::parse_grid(spun).unwrap());
+    }
+
+    #[test]
+    fn compact_counts_moved_cells() {
+        let mut grid = parse_grid("a.b|a.\n", |c| c).unwrap();
+        let moved = grid.compact(Direction4::Right, |c| c.is_alphabetic(), |c| *c == '|');
+        assert_eq!(moved, 2);
+        assert_eq!(grid, parse_grid(".ab|.a\n", |c| c).unwrap());
+
+        let mut grid = parse_grid("a\n.\nb\n.\n", |c| c).unwrap();
+        assert_eq!(grid.compact(Direction4::Down, |c| *c != '.', |_| false), 2);
+        assert_eq!(grid, parse_grid(".\n.\na\nb\n", |c| c).unwrap());
+    }
+
     // proptest
 
-    fn arbitrary_grid(width: usize, height: usize) -> impl Strategy<Value = Grid<&'static str>> {
-        let data = prop::sample::select(
+    fn letter() -> prop::sample::Select<&'static str> {
+        prop::sample::select(
             &[
                 "a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m", "n", "o", "p",
                 "q", "r", "s", "t", "u", "v", "w", "x", "y", "z",
             ][..],
-        );
+        )
+    }
+
+    fn arbitrary_grid(width: usize, height: usize) -> impl Strategy<Value = Grid<&'static str>> {
+        let data = letter();
 
         let width = 2..width;
         let height = 2..height;
@@ -1156,6 +1696,16 @@ mod tests {
             })
     }
 
+    fn arbitrary_square_grid(size: usize) -> impl Strategy<Value = Grid<&'static str>> {
+        (2..size, Just(letter()))
+            .prop_flat_map(|(size, data)| (Just(size), prop::collection::vec(data, size * size)))
+            .prop_map(|(size, data)| Grid {
+                data,
+                width: size,
+                height: size,
+            })
+    }
+
     fn arbitrary_grid_with_index(
         width: usize,
         height: usize,
@@ -1218,6 +1768,26 @@ mod tests {
             }
         }
 
+        #[test]
+        fn views_match_get(grid in arbitrary_grid(30, 30), corner in (0..30usize, 0..30usize)) {
+            for x in 0..grid.width {
+                assert!(grid.col(x).iter().eq((0..grid.height).map(|y| grid.get(x, y).unwrap())));
+            }
+            for y in 0..grid.height {
+                assert!(grid.row(y).iter().eq((0..grid.width).map(|x| grid.get(x, y).unwrap())));
+            }
+
+            let (x, y) = (corner.0.min(grid.width), corner.1.min(grid.height));
+            let view = grid.subgrid(x, y, grid.width - x, grid.height - y);
+            for ((vx, vy), tile) in view.iter() {
+                assert_eq!(grid.get(x + vx, y + vy).unwrap(), tile);
+            }
+            for vx in 0..view.width {
+                assert_eq!(view.col(vx).len(), view.height);
+                assert!(view.col(vx).iter().eq(view.rows().map(|row| &row[vx])));
+            }
+        }
+
         #[test]
         fn rotations_line_up(grid in arbitrary_grid(50, 50)) {
             assert_eq!(grid.rotate_cw().rotate_cw(), grid.rotate_ccw().rotate_ccw());
@@ -1227,5 +1797,64 @@ mod tests {
             assert_eq!(grid.rotate_cw().rotate_cw().rotate_cw(), grid.rotate_ccw());
             assert_eq!(grid.rotate_ccw().rotate_ccw().rotate_ccw(), grid.rotate_cw());
         }
+
+        #[test]
+        fn transform_moves_cells_like_apply(grid in arbitrary_grid(30, 30)) {
+            let size = (grid.width, grid.height);
+            for (symmetry, transformed) in grid.symmetries() {
+                assert_eq!((transformed.width, transformed.height), symmetry.dimensions(size));
+                for ((x, y), tile) in grid.iter() {
+                    assert_eq!(transformed.get_tuple(symmetry.apply((x, y), size)).unwrap(), tile);
+                }
+            }
+        }
+
+        #[test]
+        fn named_transforms_line_up(grid in arbitrary_grid(30, 30)) {
+            assert_eq!(grid.transform(Symmetry::RotateCw), grid.rotate_cw());
+            assert_eq!(grid.transform(Symmetry::RotateCcw), grid.rotate_ccw());
+            assert_eq!(grid.transpose().transpose(), grid);
+            assert_eq!(grid.flip_horizontal().flip_horizontal(), grid);
+            assert_eq!(grid.flip_vertical().flip_vertical(), grid);
+            assert_eq!(grid.transpose().flip_horizontal(), grid.rotate_cw());
+            assert_eq!(grid.flip_horizontal().flip_vertical(), grid.rotate_cw().rotate_cw());
+            assert_eq!(grid.transform(Symmetry::AntiTranspose), grid.rotate_cw().flip_vertical());
+            assert_eq!(grid.transpose().compute_rows(), grid.compute_columns());
+        }
+
+        #[test]
+        fn flips_in_place_match(grid in arbitrary_grid(30, 30)) {
+            let mut flipped = grid.clone();
+            flipped.flip_horizontal_in_place();
+            assert_eq!(flipped, grid.flip_horizontal());
+
+            let mut flipped = grid.clone();
+            flipped.flip_vertical_in_place();
+            assert_eq!(flipped, grid.flip_vertical());
+        }
+
+        #[test]
+        fn square_transforms_in_place_match(grid in arbitrary_square_grid(20)) {
+            for (symmetry, transformed) in grid.symmetries() {
+                let mut in_place = grid.clone();
+                in_place.transform_in_place(symmetry);
+                assert_eq!(in_place, transformed, "{symmetry:?}");
+            }
+
+            let mut rotated = grid.clone();
+            rotated.rotate_cw_in_place();
+            assert_eq!(rotated, grid.rotate_cw());
+            rotated.rotate_ccw_in_place();
+            assert_eq!(rotated, grid);
+        }
+
+        #[test]
+        fn canonical_is_shared_by_symmetries(grid in arbitrary_grid(12, 12)) {
+            let canonical = grid.canonical();
+            assert!(grid.symmetries().any(|(_, g)| g == canonical));
+            for (_, transformed) in grid.symmetries() {
+                assert_eq!(transformed.canonical(), canonical);
+            }
+        }
     }
 }
@@ -0,0 +1,526 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+};
+
+use crate::{Axis3, Grid, Point3};
+
+/// How far the shape made of `cells` can travel along `direction`, at most `limit`.
+///
+/// Cells of the shape never block each other. An empty shape or a zero `direction`
+/// never meets anything, so those are 0.
+fn drop_distance(
+    cells: &[Point3],
+    direction: Point3,
+    limit: i64,
+    is_free: impl Fn(Point3) -> bool,
+) -> i64 {
+    if cells.is_empty() || direction == Point3::new(0, 0, 0) {
+        return 0;
+    }
+    let shape = cells.iter().copied().collect::<HashSet<_>>();
+    let mut distance = 0;
+    while distance < limit {
+        let next = distance + 1;
+        if cells.iter().all(|&cell| {
+            let moved = cell + direction * next;
+            shape.contains(&moved) || is_free(moved)
+        }) {
+            distance = next;
+        } else {
+            break;
+        }
+    }
+    distance
+}
+
+/// Steps along `direction` until every one of `cells` is past `(min, max)` on some axis,
+/// from where moving further can never bring them back.
+fn steps_to_leave(cells: &[Point3], direction: Point3, (min, max): (Point3, Point3)) -> i64 {
+    [Axis3::X, Axis3::Y, Axis3::Z]
+        .into_iter()
+        .filter_map(|axis| {
+            let d = direction.get(axis);
+            let values = cells.iter().map(|cell| cell.get(axis));
+            // the cell that is the last to leave, and how far it has to go
+            let gap = match d.signum() {
+                1 => max.get(axis) - values.min()?,
+                -1 => values.max()? - min.get(axis),
+                _ => return None,
+            };
+            Some((gap.div_euclid(d.abs()) + 1).max(0))
+        })
+        .min()
+        .unwrap_or(i64::MAX)
+}
+
+/// A dense 3D grid covering `0..width`, `0..height` and `0..depth` on x, y and z
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Grid3<T> {
+    pub data: Vec<T>,
+    pub width: usize,
+    pub height: usize,
+    pub depth: usize,
+}
+
+impl<T> Grid3<T> {
+    #[must_use]
+    pub fn new(width: usize, height: usize, depth: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid3 {
+            data: vec![fill; width * height * depth],
+            width,
+            height,
+            depth,
+        }
+    }
+
+    #[must_use]
+    pub fn contains(&self, p: Point3) -> bool {
+        (0..self.width as i64).contains(&p.x)
+            && (0..self.height as i64).contains(&p.y)
+            && (0..self.depth as i64).contains(&p.z)
+    }
+
+    fn index(&self, p: Point3) -> Option<usize> {
+        self.contains(p)
+            .then(|| (p.z as usize * self.height + p.y as usize) * self.width + p.x as usize)
+    }
+
+    #[must_use]
+    pub fn reverse_index(&self, i: usize) -> Point3 {
+        Point3::new(
+            (i % self.width) as i64,
+            (i / self.width % self.height) as i64,
+            (i / (self.width * self.height)) as i64,
+        )
+    }
+
+    #[must_use]
+    pub fn get(&self, p: Point3) -> Option<&T> {
+        self.index(p).map(|i| &self.data[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point3) -> Option<&mut T> {
+        self.index(p).map(|i| &mut self.data[i])
+    }
+
+    /// panics if `p` is out of bounds
+    pub fn set(&mut self, p: Point3, value: T) {
+        let i = self
+            .index(p)
+            .unwrap_or_else(|| panic!("{p:?} is out of bounds"));
+        self.data[i] = value;
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point3, &T)> {
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(i, t)| (self.reverse_index(i), t))
+    }
+
+    /// in-bounds face neighbors, in the order of [`Point3::neighbors6`]
+    pub fn neighbors6(&self, p: Point3) -> impl Iterator<Item = Point3> + '_ {
+        p.neighbors6().filter(|&n| self.contains(n))
+    }
+
+    /// in-bounds face, edge and corner neighbors, in the order of [`Point3::neighbors26`]
+    pub fn neighbors26(&self, p: Point3) -> impl Iterator<Item = Point3> + '_ {
+        p.neighbors26().filter(|&n| self.contains(n))
+    }
+
+    /// The 2D slice perpendicular to `axis` at `index`.
+    ///
+    /// The remaining axes become x and y in order, so a z layer keeps x and y,
+    /// a y layer has x and z, and an x layer has y and z.
+    #[must_use]
+    pub fn layer(&self, axis: Axis3, index: usize) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let (width, height) = match axis {
+            Axis3::X => (self.height, self.depth),
+            Axis3::Y => (self.width, self.depth),
+            Axis3::Z => (self.width, self.height),
+        };
+        let index = index as i64;
+        let point = |a: usize, b: usize| {
+            let (a, b) = (a as i64, b as i64);
+            match axis {
+                Axis3::X => Point3::new(index, a, b),
+                Axis3::Y => Point3::new(a, index, b),
+                Axis3::Z => Point3::new(a, b, index),
+            }
+        };
+
+        let mut data = Vec::with_capacity(width * height);
+        for b in 0..height {
+            for a in 0..width {
+                data.push(
+                    self.get(point(a, b))
+                        .expect("layer index in bounds")
+                        .clone(),
+                );
+            }
+        }
+
+        Grid {
+            data,
+            width,
+            height,
+        }
+    }
+
+    /// How far `cells` can move along `direction` before leaving the grid or
+    /// overlapping a cell that is not `is_free`.
+    ///
+    /// 0 when `cells` is empty or `direction` is zero.
+    pub fn drop_distance(
+        &self,
+        cells: &[Point3],
+        direction: Point3,
+        is_free: impl Fn(&T) -> bool,
+    ) -> i64 {
+        drop_distance(cells, direction, i64::MAX, |p| {
+            self.get(p).is_some_and(&is_free)
+        })
+    }
+
+    /// Moves the values at `cells` by `offset`, leaving `vacated` behind.
+    ///
+    /// Returns the new positions of the cells.
+    pub fn move_cells(&mut self, cells: &[Point3], offset: Point3, vacated: T) -> Vec<Point3>
+    where
+        T: Clone,
+    {
+        let values = cells
+            .iter()
+            .map(|&p| {
+                let value = self.get(p).expect("cell in bounds").clone();
+                self.set(p, vacated.clone());
+                value
+            })
+            .collect::<Vec<_>>();
+
+        cells
+            .iter()
+            .zip(values)
+            .map(|(&p, value)| {
+                self.set(p + offset, value);
+                p + offset
+            })
+            .collect()
+    }
+}
+
+impl<T: Debug> Debug for Grid3<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "width={}, height={}, depth={} {{",
+            self.width, self.height, self.depth
+        )?;
+        let dbg_str = self
+            .data
+            .iter()
+            .map(|tile| format!("{tile:?}"))
+            .collect::<Vec<_>>();
+
+        let max_len = dbg_str.iter().map(String::len).max().unwrap_or(0);
+
+        for (z, layer) in dbg_str.chunks(self.width * self.height).enumerate() {
+            writeln!(f, " z={z}")?;
+            for (y, row) in layer.chunks(self.width).enumerate() {
+                let row = row
+                    .iter()
+                    .map(|s| format!("{s:max_len$}"))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                writeln!(f, " {y}\t| {row}")?;
+            }
+        }
+        write!(f, "}}")
+    }
+}
+
+/// An unbounded 3D grid that only stores occupied cells
+#[derive(Clone, PartialEq, Eq)]
+pub struct SparseGrid3<T> {
+    cells: HashMap<Point3, T>,
+}
+
+impl<T> Default for SparseGrid3<T> {
+    fn default() -> Self {
+        SparseGrid3 {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> SparseGrid3<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    #[must_use]
+    pub fn get(&self, p: Point3) -> Option<&T> {
+        self.cells.get(&p)
+    }
+
+    pub fn get_mut(&mut self, p: Point3) -> Option<&mut T> {
+        self.cells.get_mut(&p)
+    }
+
+    #[must_use]
+    pub fn contains(&self, p: Point3) -> bool {
+        self.cells.contains_key(&p)
+    }
+
+    /// returns the previous value, if any
+    pub fn set(&mut self, p: Point3, value: T) -> Option<T> {
+        self.cells.insert(p, value)
+    }
+
+    pub fn remove(&mut self, p: Point3) -> Option<T> {
+        self.cells.remove(&p)
+    }
+
+    /// occupied cells, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Point3, &T)> {
+        self.cells.iter().map(|(&p, t)| (p, t))
+    }
+
+    /// inclusive `(min, max)` corners of the occupied cells, `None` while empty
+    #[must_use]
+    pub fn bounds(&self) -> Option<(Point3, Point3)> {
+        self.cells.keys().fold(None, |acc, &p| match acc {
+            None => Some((p, p)),
+            Some((min, max)) => Some((min.min(p), max.max(p))),
+        })
+    }
+
+    /// occupied face neighbors, in the order of [`Point3::neighbors6`]
+    pub fn neighbors6(&self, p: Point3) -> impl Iterator<Item = (Point3, &T)> {
+        p.neighbors6().filter_map(|n| self.get(n).map(|t| (n, t)))
+    }
+
+    /// occupied face, edge and corner neighbors, in the order of [`Point3::neighbors26`]
+    pub fn neighbors26(&self, p: Point3) -> impl Iterator<Item = (Point3, &T)> {
+        p.neighbors26().filter_map(|n| self.get(n).map(|t| (n, t)))
+    }
+
+    /// The occupied cells of the 2D slice perpendicular to `axis` at `index`,
+    /// with the remaining axes mapped the same way as [`Grid3::layer`].
+    #[must_use]
+    pub fn layer(&self, axis: Axis3, index: i64) -> crate::SparseGrid<T>
+    where
+        T: Clone,
+    {
+        self.iter()
+            .filter(|(p, _)| p.get(axis) == index)
+            .map(|(p, t)| {
+                let coord = match axis {
+                    Axis3::X => (p.y, p.z),
+                    Axis3::Y => (p.x, p.z),
+                    Axis3::Z => (p.x, p.y),
+                };
+                (coord, t.clone())
+            })
+            .collect()
+    }
+
+    /// How far `cells` can move along `direction` while every cell they would
+    /// overlap satisfies `is_free`, which also sees empty cells as `None`.
+    ///
+    /// `None` when the shape gets past [`SparseGrid3::bounds`] without being stopped,
+    /// since nothing in the grid is left to stop it, so a floor below the occupied
+    /// cells has to be in the grid to count. `Some(0)` when `cells` is empty or
+    /// `direction` is zero.
+    pub fn drop_distance(
+        &self,
+        cells: &[Point3],
+        direction: Point3,
+        is_free: impl Fn(Point3, Option<&T>) -> bool,
+    ) -> Option<i64> {
+        if cells.is_empty() || direction == Point3::new(0, 0, 0) {
+            return Some(0);
+        }
+        let limit = steps_to_leave(cells, direction, self.bounds()?);
+        let distance = drop_distance(cells, direction, limit, |p| is_free(p, self.get(p)));
+        (distance < limit).then_some(distance)
+    }
+
+    /// Moves the values at `cells` by `offset`, returning their new positions.
+    ///
+    /// Panics if any of `cells` is empty.
+    pub fn move_cells(&mut self, cells: &[Point3], offset: Point3) -> Vec<Point3> {
+        let values = cells
+            .iter()
+            .map(|p| self.remove(*p).expect("cell is occupied"))
+            .collect::<Vec<_>>();
+
+        cells
+            .iter()
+            .zip(values)
+            .map(|(&p, value)| {
+                self.set(p + offset, value);
+                p + offset
+            })
+            .collect()
+    }
+}
+
+impl<T> FromIterator<(Point3, T)> for SparseGrid3<T> {
+    fn from_iter<I: IntoIterator<Item = (Point3, T)>>(iter: I) -> Self {
+        SparseGrid3 {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T: Debug> Debug for SparseGrid3<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dense_index_round_trip() {
+        let grid = Grid3::new(3, 4, 5, 0u8);
+        for (i, (p, _)) in grid.iter().enumerate() {
+            assert_eq!(grid.index(p), Some(i));
+        }
+        assert_eq!(grid.get(Point3::new(3, 0, 0)), None);
+        assert_eq!(grid.get(Point3::new(0, 0, -1)), None);
+    }
+
+    #[test]
+    fn dense_neighbors() {
+        let grid = Grid3::new(3, 3, 3, ());
+        assert_eq!(grid.neighbors6(Point3::new(1, 1, 1)).count(), 6);
+        assert_eq!(grid.neighbors6(Point3::ZERO).count(), 3);
+        assert_eq!(grid.neighbors26(Point3::new(1, 1, 1)).count(), 26);
+        assert_eq!(grid.neighbors26(Point3::ZERO).count(), 7);
+    }
+
+    #[test]
+    fn dense_layers() {
+        let mut grid = Grid3::new(2, 3, 4, 0);
+        for p in Point3::ZERO.box_to(Point3::new(1, 2, 3)) {
+            grid.set(p, p.x * 100 + p.y * 10 + p.z);
+        }
+
+        let z = grid.layer(Axis3::Z, 2);
+        assert_eq!((z.width, z.height), (2, 3));
+        assert_eq!(z.get(1, 2).unwrap(), &122);
+
+        let y = grid.layer(Axis3::Y, 1);
+        assert_eq!((y.width, y.height), (2, 4));
+        assert_eq!(y.get(1, 3).unwrap(), &113);
+
+        let x = grid.layer(Axis3::X, 0);
+        assert_eq!((x.width, x.height), (3, 4));
+        assert_eq!(x.get(2, 3).unwrap(), &23);
+    }
+
+    #[test]
+    fn dense_drop_bricks() {
+        let mut grid = Grid3::new(3, 3, 10, None);
+        let floor = Point3::new(0, 0, 0).line_to(Point3::new(2, 0, 0));
+        for p in floor {
+            grid.set(p, Some('A'));
+        }
+
+        let brick = Point3::new(0, 0, 6)
+            .line_to(Point3::new(0, 2, 6))
+            .collect::<Vec<_>>();
+        for &p in &brick {
+            grid.set(p, Some('B'));
+        }
+
+        let down = Point3::new(0, 0, -1);
+        let distance = grid.drop_distance(&brick, down, Option::is_none);
+        assert_eq!(distance, 5);
+
+        let moved = grid.move_cells(&brick, down * distance, None);
+        assert_eq!(moved[0], Point3::new(0, 0, 1));
+        assert_eq!(grid.get(Point3::new(0, 1, 1)), Some(&Some('B')));
+        assert_eq!(grid.get(Point3::new(0, 1, 6)), Some(&None));
+
+        // vertical bricks don't block themselves
+        let tower = Point3::new(2, 2, 3)
+            .line_to(Point3::new(2, 2, 5))
+            .collect::<Vec<_>>();
+        assert_eq!(grid.drop_distance(&tower, down, Option::is_none), 3);
+
+        assert_eq!(grid.drop_distance(&[], down, Option::is_none), 0);
+        assert_eq!(
+            grid.drop_distance(&tower, Point3::new(0, 0, 0), Option::is_none),
+            0
+        );
+    }
+
+    #[test]
+    fn sparse_drop_with_floor() {
+        let mut grid = SparseGrid3::new();
+        grid.set(Point3::new(0, 0, 1), 'A');
+        grid.set(Point3::new(1, 0, 1), 'A');
+        grid.set(Point3::new(1, 0, 8), 'B');
+
+        let above_floor = |p: Point3, t: Option<&char>| p.z > 0 && t.is_none();
+        let down = Point3::new(0, 0, -1);
+        let b = [Point3::new(1, 0, 8)];
+
+        let distance = grid.drop_distance(&b, down, above_floor).unwrap();
+        assert_eq!(distance, 6);
+        grid.move_cells(&b, down * distance);
+        assert_eq!(grid.get(Point3::new(1, 0, 2)), Some(&'B'));
+        assert_eq!(
+            grid.bounds(),
+            Some((Point3::new(0, 0, 1), Point3::new(1, 0, 2)))
+        );
+        assert_eq!(grid.neighbors6(Point3::new(1, 0, 1)).count(), 2);
+
+        let layer = grid.layer(Axis3::Z, 1);
+        assert_eq!(layer.len(), 2);
+        assert_eq!(layer.get(1, 0), Some(&'A'));
+
+        let anywhere = |_: Point3, t: Option<&char>| t.is_none();
+        assert_eq!(grid.drop_distance(&[], down, anywhere), Some(0));
+        // nothing is below (5, 0, 9), so it falls out of the bounds
+        assert_eq!(
+            grid.drop_distance(&[Point3::new(5, 0, 9)], down, anywhere),
+            None
+        );
+        assert_eq!(
+            grid.drop_distance(&[Point3::new(0, 0, 9)], down, anywhere),
+            Some(7)
+        );
+        assert_eq!(
+            grid.drop_distance(&[Point3::new(0, 0, 9)], down * -1, anywhere),
+            None
+        );
+        assert_eq!(
+            SparseGrid3::<char>::new().drop_distance(&b, down, anywhere),
+            None
+        );
+    }
+}
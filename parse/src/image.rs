@@ -0,0 +1,412 @@
+use std::{
+    collections::HashSet,
+    fmt::{Display, Write as _},
+    io::Write,
+};
+
+use itertools::Itertools;
+use miette::Result;
+use miette_pretty::Pretty;
+
+use crate::{Grid, Point};
+
+/// pixels per cell when [`Grid::image_to_file`] writes a raster image
+const RASTER_SCALE: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb::new(0, 0, 0);
+    pub const WHITE: Rgb = Rgb::new(255, 255, 255);
+    pub const GREY: Rgb = Rgb::new(128, 128, 128);
+    pub const RED: Rgb = Rgb::new(255, 0, 0);
+    pub const GREEN: Rgb = Rgb::new(0, 255, 0);
+    pub const BLUE: Rgb = Rgb::new(0, 0, 255);
+    pub const YELLOW: Rgb = Rgb::new(255, 255, 0);
+
+    #[must_use]
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Rgb { r, g, b }
+    }
+}
+
+/// `#rrggbb`, as used by SVG
+impl Display for Rgb {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+/// Something drawn over the cells of an exported grid
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Overlay {
+    /// a line through the centers of consecutive points, which need not be neighbors
+    Path { points: Vec<Point>, colour: Rgb },
+    /// cells painted over completely
+    Highlight { cells: HashSet<Point>, colour: Rgb },
+}
+
+impl Overlay {
+    pub fn path(points: impl IntoIterator<Item = impl Into<Point>>, colour: Rgb) -> Self {
+        Overlay::Path {
+            points: points.into_iter().map(Into::into).collect(),
+            colour,
+        }
+    }
+
+    pub fn highlight(cells: impl IntoIterator<Item = impl Into<Point>>, colour: Rgb) -> Self {
+        Overlay::Highlight {
+            cells: cells.into_iter().map(Into::into).collect(),
+            colour,
+        }
+    }
+
+    #[must_use]
+    pub fn colour(&self) -> Rgb {
+        match self {
+            Overlay::Path { colour, .. } | Overlay::Highlight { colour, .. } => *colour,
+        }
+    }
+
+    /// every cell the overlay covers when drawn one cell at a time
+    #[must_use]
+    pub fn cells(&self) -> Vec<Point> {
+        match self {
+            Overlay::Path { points, .. } if points.len() == 1 => points.clone(),
+            Overlay::Path { points, .. } => points
+                .windows(2)
+                .flat_map(|pair| line_cells(pair[0], pair[1]))
+                .collect(),
+            Overlay::Highlight { cells, .. } => cells.iter().copied().collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImageFormat {
+    Ppm,
+    Png,
+    Svg,
+}
+
+impl ImageFormat {
+    #[must_use]
+    pub fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Ppm => "ppm",
+            ImageFormat::Png => "png",
+            ImageFormat::Svg => "svg",
+        }
+    }
+}
+
+/// A raster image, stored row by row like [`Grid`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub pixels: Vec<Rgb>,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Image {
+    #[must_use]
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Image {
+            pixels: vec![background; width * height],
+            width,
+            height,
+        }
+    }
+
+    #[must_use]
+    pub fn get(&self, x: usize, y: usize) -> Option<Rgb> {
+        (x < self.width && y < self.height).then(|| self.pixels[y * self.width + x])
+    }
+
+    /// Pixels outside the image are ignored.
+    pub fn set(&mut self, x: usize, y: usize, colour: Rgb) {
+        if x < self.width && y < self.height {
+            self.pixels[y * self.width + x] = colour;
+        }
+    }
+
+    /// every pixel blown up into a `factor` by `factor` square
+    #[must_use]
+    pub fn scaled(&self, factor: usize) -> Image {
+        let width = self.width * factor;
+        Image {
+            pixels: (0..self.height * factor)
+                .flat_map(|y| (0..width).map(move |x| (x / factor, y / factor)))
+                .map(|(x, y)| self.pixels[y * self.width + x])
+                .collect(),
+            width,
+            height: self.height * factor,
+        }
+    }
+
+    fn bytes(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|&Rgb { r, g, b }| [r, g, b])
+            .collect()
+    }
+
+    /// binary PPM, the simplest format most image viewers open
+    pub fn write_ppm(&self, mut writer: impl Write) -> Result<()> {
+        write!(writer, "P6\n{} {}\n255\n", self.width, self.height).pretty_msg("writing ppm")?;
+        writer.write_all(&self.bytes()).pretty_msg("writing ppm")
+    }
+
+    pub fn write_png(&self, writer: impl Write) -> Result<()> {
+        let mut encoder = png::Encoder::new(writer, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().pretty_msg("writing png header")?;
+        writer
+            .write_image_data(&self.bytes())
+            .pretty_msg("writing png")
+    }
+}
+
+/// Every cell on the straight line from `from` to `to`, both included.
+fn line_cells(from: Point, to: Point) -> impl Iterator<Item = Point> {
+    let (dx, dy) = (
+        to.x.abs_diff(from.x) as isize,
+        to.y.abs_diff(from.y) as isize,
+    );
+    let (step_x, step_y) = (
+        if to.x >= from.x { 1 } else { -1 },
+        if to.y >= from.y { 1 } else { -1 },
+    );
+    let (mut x, mut y) = (from.x as isize, from.y as isize);
+    let mut error = dx - dy;
+
+    (0..=dx.max(dy)).map(move |_| {
+        let cell = Point::new(x as usize, y as usize);
+        let doubled = 2 * error;
+        if doubled > -dy {
+            error -= dy;
+            x += step_x;
+        }
+        if doubled < dx {
+            error += dx;
+            y += step_y;
+        }
+        cell
+    })
+}
+
+impl<T> Grid<T> {
+    /// One pixel per cell, coloured by `colour`, with `overlays` drawn on top in order.
+    pub fn to_image(&self, colour: impl Fn(&T) -> Rgb, overlays: &[Overlay]) -> Image {
+        let mut image = Image {
+            pixels: self.data.iter().map(colour).collect(),
+            width: self.width,
+            height: self.height,
+        };
+        for overlay in overlays {
+            for cell in overlay.cells() {
+                image.set(cell.x, cell.y, overlay.colour());
+            }
+        }
+        image
+    }
+
+    /// A square of side 1 per cell, with runs of same coloured cells in a row merged into
+    /// one rectangle to keep the file small.
+    pub fn to_svg(&self, colour: impl Fn(&T) -> Rgb, overlays: &[Overlay]) -> String {
+        let mut svg = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {w} {h}" width="{}" height="{}" shape-rendering="crispEdges">"#,
+            self.width * RASTER_SCALE,
+            self.height * RASTER_SCALE,
+            w = self.width,
+            h = self.height,
+        );
+        svg.push('\n');
+
+        for (y, row) in self.rows().enumerate() {
+            let mut x = 0;
+            let runs = row.iter().map(&colour).group_by(|&fill| fill);
+            for (fill, run) in &runs {
+                let len = run.count();
+                writeln!(
+                    svg,
+                    r#"<rect x="{x}" y="{y}" width="{len}" height="1" fill="{fill}"/>"#
+                )
+                .expect("writing to a String");
+                x += len;
+            }
+        }
+
+        for overlay in overlays {
+            match overlay {
+                Overlay::Path { points, colour } => {
+                    let points = points
+                        .iter()
+                        .map(|p| format!("{}.5,{}.5", p.x, p.y))
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    writeln!(
+                        svg,
+                        r#"<polyline points="{points}" fill="none" stroke="{colour}" stroke-width="0.3" stroke-linecap="round" stroke-linejoin="round"/>"#
+                    )
+                    .expect("writing to a String");
+                }
+                Overlay::Highlight { cells, colour } => {
+                    let mut cells = cells.iter().collect::<Vec<_>>();
+                    cells.sort_unstable_by_key(|p| (p.y, p.x));
+                    for cell in cells {
+                        writeln!(
+                            svg,
+                            r#"<rect x="{}" y="{}" width="1" height="1" fill="{colour}"/>"#,
+                            cell.x, cell.y
+                        )
+                        .expect("writing to a String");
+                    }
+                }
+            }
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Writes the grid as a picture to `<name>.<extension>`, like [`Grid::debug_to_file`]
+    /// but readable for large grids. Raster formats use a few pixels per cell.
+    pub fn image_to_file(
+        &self,
+        name: impl Display,
+        format: ImageFormat,
+        colour: impl Fn(&T) -> Rgb,
+        overlays: &[Overlay],
+    ) -> Result<()> {
+        use std::fs::File;
+        use std::io::BufWriter;
+
+        let path = format!("{}.{}", name, format.extension());
+        let file = File::create(path).pretty_msg("creating file")?;
+        let mut file = BufWriter::new(file);
+        match format {
+            ImageFormat::Ppm => self
+                .to_image(colour, overlays)
+                .scaled(RASTER_SCALE)
+                .write_ppm(&mut file)?,
+            ImageFormat::Png => self
+                .to_image(colour, overlays)
+                .scaled(RASTER_SCALE)
+                .write_png(&mut file)?,
+            ImageFormat::Svg => file
+                .write_all(self.to_svg(colour, overlays).as_bytes())
+                .pretty_msg("writing svg")?,
+        }
+        file.flush().pretty_msg("writing image")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_grid;
+    use indoc::indoc;
+
+    fn example() -> Grid<char> {
+        parse_grid(
+            indoc! {r#"
+                .#..
+                ....
+                ..#.
+            "#},
+            |c| c,
+        )
+        .unwrap()
+    }
+
+    fn walls(tile: &char) -> Rgb {
+        match tile {
+            '#' => Rgb::BLACK,
+            _ => Rgb::WHITE,
+        }
+    }
+
+    #[test]
+    fn line_cells() {
+        let line = super::line_cells(Point::new(0, 0), Point::new(3, 1)).collect::<Vec<_>>();
+        assert_eq!(line.first(), Some(&Point::new(0, 0)));
+        assert_eq!(line.last(), Some(&Point::new(3, 1)));
+        assert_eq!(line.len(), 4);
+
+        let back = super::line_cells(Point::new(2, 3), Point::new(2, 0)).collect::<Vec<_>>();
+        assert_eq!(
+            back,
+            (0..=3).rev().map(|y| Point::new(2, y)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn to_image_with_overlays() {
+        let grid = example();
+        let image = grid.to_image(
+            walls,
+            &[
+                Overlay::path([(0, 0), (0, 2), (1, 2)], Rgb::RED),
+                Overlay::highlight([(3, 0)], Rgb::BLUE),
+            ],
+        );
+        assert_eq!((image.width, image.height), (4, 3));
+        assert_eq!(image.get(1, 0), Some(Rgb::BLACK));
+        assert_eq!(image.get(2, 1), Some(Rgb::WHITE));
+        assert_eq!(image.get(0, 1), Some(Rgb::RED));
+        assert_eq!(image.get(1, 2), Some(Rgb::RED));
+        assert_eq!(image.get(3, 0), Some(Rgb::BLUE));
+        assert_eq!(image.get(4, 0), None);
+
+        let scaled = image.scaled(2);
+        assert_eq!((scaled.width, scaled.height), (8, 6));
+        assert_eq!(scaled.get(3, 1), Some(Rgb::BLACK));
+        assert_eq!(scaled.get(7, 0), Some(Rgb::BLUE));
+    }
+
+    #[test]
+    fn write_ppm() {
+        let image = example().to_image(walls, &[]);
+        let mut bytes = Vec::new();
+        image.write_ppm(&mut bytes).unwrap();
+        let header = b"P6\n4 3\n255\n";
+        assert_eq!(&bytes[..header.len()], header);
+        assert_eq!(bytes.len(), header.len() + 4 * 3 * 3);
+        assert_eq!(
+            &bytes[header.len()..header.len() + 6],
+            &[255, 255, 255, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn write_png_round_trips() {
+        let image = example().to_image(walls, &[Overlay::highlight([(2, 1)], Rgb::GREEN)]);
+        let mut bytes = Vec::new();
+        image.write_png(&mut bytes).unwrap();
+
+        let mut reader = png::Decoder::new(bytes.as_slice()).read_info().unwrap();
+        let mut decoded = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut decoded).unwrap();
+        assert_eq!((info.width, info.height), (4, 3));
+        assert_eq!(&decoded[..info.buffer_size()], image.bytes().as_slice());
+    }
+
+    #[test]
+    fn to_svg() {
+        let svg = example().to_svg(walls, &[Overlay::path([(0, 0), (3, 0)], Rgb::RED)]);
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains(r#"viewBox="0 0 4 3""#));
+        // the empty middle row is a single run
+        assert!(svg.contains(r##"<rect x="0" y="1" width="4" height="1" fill="#ffffff"/>"##));
+        assert!(svg.contains(r##"<rect x="1" y="0" width="1" height="1" fill="#000000"/>"##));
+        assert!(svg.contains(r##"points="0.5,0.5 3.5,0.5" fill="none" stroke="#ff0000""##));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+}
@@ -1,11 +1,70 @@
+mod beam;
+mod bit_grid;
+mod components;
+mod corridor;
+mod diff;
+mod direction;
+mod distance;
 mod grid;
+mod grid3;
+mod image;
 mod pattern_enum;
+mod point;
+mod point3;
+mod polygon;
 mod quick_regex;
+mod reflection;
+pub mod search;
+mod sparse;
+mod stencil;
+mod symmetry;
+mod terminal;
+mod tiled;
+mod view;
 
+pub use beam::Propagation;
+pub use beam::Ray;
+pub use bit_grid::BitGrid;
+pub use components::Components;
+pub use components::Region;
+pub use corridor::contract_corridors;
+pub use corridor::Corridor;
+pub use corridor::JunctionGraph;
+pub use diff::Change;
+pub use diff::Diff;
+pub use direction::Direction4;
+pub use direction::Direction8;
+pub use grid::grid_dimensions;
 pub use grid::parse_grid;
 pub use grid::Grid;
+pub use grid::GridError;
 pub use grid::Relationship;
+pub use grid3::Grid3;
+pub use grid3::SparseGrid3;
+pub use image::Image;
+pub use image::ImageFormat;
+pub use image::Overlay;
+pub use image::Rgb;
+pub use point::Point;
+pub use point::Vec2;
+pub use point3::Axis3;
+pub use point3::Point3;
+pub use polygon::Containment;
+pub use polygon::Polygon;
 pub use quick_regex::QuickRegex;
+pub use reflection::Reflection;
+pub use reflection::ReflectionAxis;
+pub use sparse::Bounds;
+pub use sparse::SparseGrid;
+pub use stencil::Stencil;
+pub use symmetry::Symmetry;
+pub use terminal::Render;
+pub use terminal::Style;
+pub use tiled::TiledGrid;
+pub use view::Column;
+pub use view::SubGrid;
 
 pub use ::const_str as macro_const_str;
 pub use ::paste as macro_paste;
+pub use ::smallvec::smallvec;
+pub use ::smallvec::SmallVec;
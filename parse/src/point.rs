@@ -0,0 +1,247 @@
+use std::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// A cell in a [`crate::Grid`], x is the column, y is the row
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+/// A signed offset between two [`Point`]s
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Vec2 {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    #[must_use]
+    pub const fn new(x: usize, y: usize) -> Self {
+        Point { x, y }
+    }
+
+    /// `None` if the result would have a negative component
+    #[must_use]
+    pub fn checked_add(self, rhs: impl Into<Vec2>) -> Option<Self> {
+        let rhs = rhs.into();
+        Some(Point {
+            x: self.x.checked_add_signed(rhs.x)?,
+            y: self.y.checked_add_signed(rhs.y)?,
+        })
+    }
+
+    /// The offset that moves `other` onto `self`
+    #[must_use]
+    pub fn offset_from(self, other: Point) -> Vec2 {
+        Vec2 {
+            x: self.x as isize - other.x as isize,
+            y: self.y as isize - other.y as isize,
+        }
+    }
+
+    #[must_use]
+    pub fn manhattan(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2 { x: 0, y: 0 };
+
+    #[must_use]
+    pub const fn new(x: isize, y: isize) -> Self {
+        Vec2 { x, y }
+    }
+
+    #[must_use]
+    pub fn manhattan(self) -> usize {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+
+    #[must_use]
+    pub fn signum(self) -> Self {
+        Vec2 {
+            x: self.x.signum(),
+            y: self.y.signum(),
+        }
+    }
+}
+
+impl Debug for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Debug for Vec2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<{}, {}>", self.x, self.y)
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Point { x, y }
+    }
+}
+
+impl From<Point> for (usize, usize) {
+    fn from(point: Point) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl From<(isize, isize)> for Vec2 {
+    fn from((x, y): (isize, isize)) -> Self {
+        Vec2 { x, y }
+    }
+}
+
+impl From<Vec2> for (isize, isize) {
+    fn from(vec: Vec2) -> Self {
+        (vec.x, vec.y)
+    }
+}
+
+// operators
+
+/// Panics if the result would have a negative component, see [`Point::checked_add`]
+impl<V: Into<Vec2>> Add<V> for Point {
+    type Output = Point;
+
+    fn add(self, rhs: V) -> Self::Output {
+        self.checked_add(rhs)
+            .unwrap_or_else(|| panic!("{self:?} + offset is out of range"))
+    }
+}
+
+impl<V: Into<Vec2>> AddAssign<V> for Point {
+    fn add_assign(&mut self, rhs: V) {
+        *self = *self + rhs;
+    }
+}
+
+impl<V: Into<Vec2>> Sub<V> for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: V) -> Self::Output {
+        self + -rhs.into()
+    }
+}
+
+impl<V: Into<Vec2>> SubAssign<V> for Point {
+    fn sub_assign(&mut self, rhs: V) {
+        *self = *self - rhs;
+    }
+}
+
+impl<V: Into<Vec2>> Add<V> for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, rhs: V) -> Self::Output {
+        let rhs = rhs.into();
+        Vec2 {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl<V: Into<Vec2>> AddAssign<V> for Vec2 {
+    fn add_assign(&mut self, rhs: V) {
+        *self = *self + rhs;
+    }
+}
+
+impl<V: Into<Vec2>> Sub<V> for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, rhs: V) -> Self::Output {
+        self + -rhs.into()
+    }
+}
+
+impl<V: Into<Vec2>> SubAssign<V> for Vec2 {
+    fn sub_assign(&mut self, rhs: V) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Self::Output {
+        Vec2 {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl Mul<isize> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Vec2 {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_arithmetic() {
+        let p = Point::new(3, 4);
+        assert_eq!(p + Vec2::new(1, -2), Point::new(4, 2));
+        assert_eq!(p - Vec2::new(1, -2), Point::new(2, 6));
+        assert_eq!(p + (-3, -4), Point::new(0, 0));
+        assert_eq!(p.checked_add((-4, 0)), None);
+        assert_eq!(Point::new(5, 1).offset_from(p), Vec2::new(2, -3));
+        assert_eq!(p.manhattan(Point::new(0, 10)), 9);
+
+        let mut q = p;
+        q += Vec2::new(2, 2);
+        q -= (1, 1);
+        assert_eq!(q, Point::new(4, 5));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn point_underflow_panics() {
+        let _ = Point::new(0, 0) + Vec2::new(-1, 0);
+    }
+
+    #[test]
+    fn vec2_arithmetic() {
+        let v = Vec2::new(2, -3);
+        assert_eq!(v + v, v * 2);
+        assert_eq!(v - v, Vec2::ZERO);
+        assert_eq!(-v, Vec2::new(-2, 3));
+        assert_eq!(v.manhattan(), 5);
+        assert_eq!((v * 7).signum(), Vec2::new(1, -1));
+    }
+
+    #[test]
+    fn tuple_conversions() {
+        let p: Point = (1, 2).into();
+        let t: (usize, usize) = p.into();
+        assert_eq!(t, (1, 2));
+
+        let v: Vec2 = (-1, 2).into();
+        let t: (isize, isize) = v.into();
+        assert_eq!(t, (-1, 2));
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", Point::new(1, 2)), "(1, 2)");
+        assert_eq!(format!("{:?}", Vec2::new(-1, 2)), "<-1, 2>");
+    }
+}
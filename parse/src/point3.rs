@@ -0,0 +1,255 @@
+use std::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use itertools::Itertools;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Axis3 {
+    X,
+    Y,
+    Z,
+}
+
+/// A signed 3D coordinate, also used for offsets between coordinates
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub const ZERO: Point3 = Point3::new(0, 0, 0);
+
+    #[must_use]
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Point3 { x, y, z }
+    }
+
+    /// unit vector pointing along `axis`
+    #[must_use]
+    pub fn unit(axis: Axis3) -> Self {
+        match axis {
+            Axis3::X => Point3::new(1, 0, 0),
+            Axis3::Y => Point3::new(0, 1, 0),
+            Axis3::Z => Point3::new(0, 0, 1),
+        }
+    }
+
+    #[must_use]
+    pub fn get(self, axis: Axis3) -> i64 {
+        match axis {
+            Axis3::X => self.x,
+            Axis3::Y => self.y,
+            Axis3::Z => self.z,
+        }
+    }
+
+    #[must_use]
+    pub fn with(self, axis: Axis3, value: i64) -> Self {
+        match axis {
+            Axis3::X => Point3 { x: value, ..self },
+            Axis3::Y => Point3 { y: value, ..self },
+            Axis3::Z => Point3 { z: value, ..self },
+        }
+    }
+
+    #[must_use]
+    pub fn manhattan(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    #[must_use]
+    pub fn signum(self) -> Self {
+        Point3::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    #[must_use]
+    pub fn min(self, other: Self) -> Self {
+        Point3::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    #[must_use]
+    pub fn max(self, other: Self) -> Self {
+        Point3::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+
+    /// the 6 face neighbors, ordered -x, +x, -y, +y, -z, +z
+    pub fn neighbors6(self) -> impl Iterator<Item = Point3> {
+        [
+            Point3::new(-1, 0, 0),
+            Point3::new(1, 0, 0),
+            Point3::new(0, -1, 0),
+            Point3::new(0, 1, 0),
+            Point3::new(0, 0, -1),
+            Point3::new(0, 0, 1),
+        ]
+        .into_iter()
+        .map(move |d| self + d)
+    }
+
+    /// the 26 face, edge and corner neighbors, with x varying fastest and z slowest
+    pub fn neighbors26(self) -> impl Iterator<Item = Point3> {
+        Point3::new(-1, -1, -1)
+            .box_to(Point3::new(1, 1, 1))
+            .filter(|&d| d != Point3::ZERO)
+            .map(move |d| self + d)
+    }
+
+    /// Every point from `self` to `end` inclusive, in order.
+    ///
+    /// Panics if the two points differ in more than one axis.
+    pub fn line_to(self, end: Self) -> impl Iterator<Item = Point3> {
+        let delta = end - self;
+        assert!(
+            [delta.x, delta.y, delta.z]
+                .into_iter()
+                .filter(|&d| d != 0)
+                .count()
+                <= 1,
+            "{self:?} to {end:?} is not axis-aligned"
+        );
+        let step = delta.signum();
+        let len = delta.x.abs() + delta.y.abs() + delta.z.abs();
+        (0..=len).map(move |i| self + step * i)
+    }
+
+    /// Every point in the box with corners `self` and `end` inclusive.
+    ///
+    /// x varies fastest and z slowest, regardless of which corner is smaller.
+    pub fn box_to(self, end: Self) -> impl Iterator<Item = Point3> {
+        let (min, max) = (self.min(end), self.max(end));
+        (min.z..=max.z)
+            .cartesian_product(min.y..=max.y)
+            .cartesian_product(min.x..=max.x)
+            .map(|((z, y), x)| Point3::new(x, y, z))
+    }
+}
+
+impl Debug for Point3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl From<(i64, i64, i64)> for Point3 {
+    fn from((x, y, z): (i64, i64, i64)) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl From<Point3> for (i64, i64, i64) {
+    fn from(point: Point3) -> Self {
+        (point.x, point.y, point.z)
+    }
+}
+
+// operators
+
+macro_rules! impl_op {
+    ($trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident, $op:tt) => {
+        impl $trait for Point3 {
+            type Output = Point3;
+
+            fn $fn(self, rhs: Self) -> Self::Output {
+                Point3 {
+                    x: self.x $op rhs.x,
+                    y: self.y $op rhs.y,
+                    z: self.z $op rhs.z,
+                }
+            }
+        }
+
+        impl $assign_trait for Point3 {
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+}
+
+impl_op!(Add, add, AddAssign, add_assign, +);
+impl_op!(Sub, sub, SubAssign, sub_assign, -);
+
+impl Neg for Point3 {
+    type Output = Point3;
+
+    fn neg(self) -> Self::Output {
+        Point3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Mul<i64> for Point3 {
+    type Output = Point3;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Point3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Point3::new(1, 2, 3);
+        let b = Point3::new(-1, 0, 5);
+        assert_eq!(a + b, Point3::new(0, 2, 8));
+        assert_eq!(a - b, Point3::new(2, 2, -2));
+        assert_eq!(-a, Point3::new(-1, -2, -3));
+        assert_eq!(a * 3, Point3::new(3, 6, 9));
+        assert_eq!(a.manhattan(b), 6);
+        assert_eq!(a.with(Axis3::Z, 0).get(Axis3::Z), 0);
+    }
+
+    #[test]
+    fn line_to() {
+        let a = Point3::new(1, 0, 1);
+        assert_eq!(
+            a.line_to(Point3::new(1, 0, 3)).collect::<Vec<_>>(),
+            vec![a, Point3::new(1, 0, 2), Point3::new(1, 0, 3)]
+        );
+        assert_eq!(
+            a.line_to(Point3::new(-1, 0, 1)).collect::<Vec<_>>(),
+            vec![a, Point3::new(0, 0, 1), Point3::new(-1, 0, 1)]
+        );
+        assert_eq!(a.line_to(a).collect::<Vec<_>>(), vec![a]);
+    }
+
+    #[test]
+    #[should_panic(expected = "not axis-aligned")]
+    fn line_to_diagonal_panics() {
+        let _ = Point3::ZERO.line_to(Point3::new(1, 1, 0));
+    }
+
+    #[test]
+    fn box_to() {
+        let points = Point3::new(1, 1, 1)
+            .box_to(Point3::new(0, 0, 0))
+            .collect::<Vec<_>>();
+        assert_eq!(points.len(), 8);
+        assert_eq!(points[0], Point3::ZERO);
+        assert_eq!(points[1], Point3::new(1, 0, 0));
+        assert_eq!(points[7], Point3::new(1, 1, 1));
+    }
+
+    #[test]
+    fn neighbors() {
+        let p = Point3::new(5, 5, 5);
+        assert_eq!(p.neighbors6().count(), 6);
+        assert!(p.neighbors6().all(|n| n.manhattan(p) == 1));
+        assert_eq!(p.neighbors26().count(), 26);
+        assert!(p.neighbors26().all(|n| n != p && (n - p).signum() == n - p));
+    }
+}
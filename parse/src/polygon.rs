@@ -0,0 +1,265 @@
+use crate::Vec2;
+
+/// Where a lattice point lies relative to a [`Polygon`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Containment {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+/// A simple polygon with integer vertices, using exact integer arithmetic throughout.
+///
+/// Vertices are in order around the polygon, either direction, and the closing edge
+/// back to the first vertex is implied.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Polygon {
+    pub vertices: Vec<(i64, i64)>,
+}
+
+impl Polygon {
+    /// a trailing copy of the first vertex is dropped
+    #[must_use]
+    pub fn new(mut vertices: Vec<(i64, i64)>) -> Self {
+        if vertices.len() > 1 && vertices.first() == vertices.last() {
+            vertices.pop();
+        }
+        Polygon { vertices }
+    }
+
+    /// Follows `moves` from `(0, 0)`, such as a dig plan of `(Direction4, length)`.
+    pub fn from_moves<D: Into<Vec2>>(moves: impl IntoIterator<Item = (D, i64)>) -> Self {
+        let mut position = (0, 0);
+        let mut vertices = vec![position];
+        for (direction, length) in moves {
+            let delta = direction.into();
+            position.0 += delta.x as i64 * length;
+            position.1 += delta.y as i64 * length;
+            vertices.push(position);
+        }
+        Polygon::new(vertices)
+    }
+
+    fn edges(&self) -> impl Iterator<Item = ((i128, i128), (i128, i128))> + '_ {
+        let widen = |(x, y): (i64, i64)| (i128::from(x), i128::from(y));
+        self.vertices
+            .iter()
+            .zip(self.vertices.iter().cycle().skip(1))
+            .map(move |(&a, &b)| (widen(a), widen(b)))
+    }
+
+    /// Shoelace formula, positive when the vertices go counter-clockwise with y pointing up.
+    ///
+    /// Doubled so that it is always an integer.
+    #[must_use]
+    pub fn signed_double_area(&self) -> i128 {
+        self.edges()
+            .map(|((x1, y1), (x2, y2))| x1 * y2 - x2 * y1)
+            .sum()
+    }
+
+    /// Twice the enclosed area, always an integer for lattice polygons
+    #[must_use]
+    pub fn double_area(&self) -> u128 {
+        self.signed_double_area().unsigned_abs()
+    }
+
+    /// Number of lattice points on the edges, including the vertices
+    #[must_use]
+    pub fn boundary_points(&self) -> u128 {
+        self.edges()
+            .map(|((x1, y1), (x2, y2))| gcd((x2 - x1).unsigned_abs(), (y2 - y1).unsigned_abs()))
+            .sum()
+    }
+
+    /// Total length of the edges, the same as [`Polygon::boundary_points`] when every
+    /// edge is horizontal or vertical
+    #[must_use]
+    pub fn perimeter(&self) -> u128 {
+        self.edges()
+            .map(|((x1, y1), (x2, y2))| (x2 - x1).unsigned_abs() + (y2 - y1).unsigned_abs())
+            .sum()
+    }
+
+    /// Lattice points strictly inside, by Pick's theorem.
+    ///
+    /// Zero when the polygon encloses no area, such as fewer than three vertices or
+    /// vertices that are all on one line.
+    #[must_use]
+    pub fn interior_points(&self) -> u128 {
+        match self.double_area() {
+            0 => 0,
+            double_area => (double_area + 2).saturating_sub(self.boundary_points()) / 2,
+        }
+    }
+
+    /// Lattice points inside or on the boundary.
+    ///
+    /// When every vertex is a grid cell, this is the number of cells enclosed by the
+    /// loop including the loop itself, as in a dig plan.
+    #[must_use]
+    pub fn lattice_points(&self) -> u128 {
+        self.interior_points() + self.boundary_points()
+    }
+
+    #[must_use]
+    pub fn contains(&self, (x, y): (i64, i64)) -> Containment {
+        let (px, py) = (i128::from(x), i128::from(y));
+        let mut inside = false;
+
+        for ((x1, y1), (x2, y2)) in self.edges() {
+            let cross = (x2 - x1) * (py - y1) - (px - x1) * (y2 - y1);
+            let within =
+                (x1.min(x2)..=x1.max(x2)).contains(&px) && (y1.min(y2)..=y1.max(y2)).contains(&py);
+            if cross == 0 && within {
+                return Containment::Boundary;
+            }
+
+            // count crossings of a ray towards +x, half-open in y so vertices count once
+            if (y1 > py) != (y2 > py) && (cross > 0) == (y2 > y1) {
+                inside = !inside;
+            }
+        }
+
+        if inside {
+            Containment::Inside
+        } else {
+            Containment::Outside
+        }
+    }
+
+    /// Convenience for grid cells, see [`Polygon::contains`]
+    #[must_use]
+    pub fn contains_cell(&self, (x, y): (usize, usize)) -> Containment {
+        self.contains((x as i64, y as i64))
+    }
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Direction4, QuickRegex};
+    use indoc::indoc;
+
+    const DIG_PLAN: &str = indoc! {r#"
+        R 6 (#70c710)
+        D 5 (#0dc571)
+        L 2 (#5713f0)
+        D 2 (#d2c081)
+        R 2 (#59c680)
+        D 2 (#411b91)
+        L 5 (#8ceee2)
+        U 2 (#caa173)
+        L 1 (#1b58a2)
+        U 2 (#caa171)
+        R 2 (#7807d2)
+        U 3 (#a77fa3)
+        L 2 (#015232)
+        U 2 (#7a21e3)
+    "#};
+
+    #[test]
+    fn aoc2023_day18_part1_example() {
+        let moves = DIG_PLAN.lines().map(|l| {
+            let direction = match &l[..1] {
+                "U" => Direction4::Up,
+                "D" => Direction4::Down,
+                "L" => Direction4::Left,
+                _ => Direction4::Right,
+            };
+            (direction, l.get_digits().unwrap()[0])
+        });
+        let polygon = Polygon::from_moves(moves);
+
+        assert_eq!(polygon.boundary_points(), 38);
+        assert_eq!(polygon.perimeter(), 38);
+        assert_eq!(polygon.lattice_points(), 62);
+    }
+
+    #[test]
+    fn aoc2023_day18_part2_example() {
+        let moves = DIG_PLAN.lines().map(|l| {
+            let color = l.get_match(r"#\w+").unwrap();
+            let direction = match &color[6..] {
+                "0" => Direction4::Right,
+                "1" => Direction4::Down,
+                "2" => Direction4::Left,
+                _ => Direction4::Up,
+            };
+            (direction, i64::from_str_radix(&color[1..6], 16).unwrap())
+        });
+
+        assert_eq!(Polygon::from_moves(moves).lattice_points(), 952_408_144_115);
+    }
+
+    #[test]
+    fn triangle_with_diagonal_edge() {
+        let polygon = Polygon::new(vec![(0, 0), (4, 0), (0, 4)]);
+        assert_eq!(polygon.double_area(), 16);
+        assert_eq!(polygon.boundary_points(), 12);
+        assert_eq!(polygon.interior_points(), 3);
+        assert_eq!(polygon.signed_double_area(), 16);
+        assert_eq!(
+            Polygon::new(vec![(0, 0), (0, 4), (4, 0), (0, 0)]).signed_double_area(),
+            -16
+        );
+    }
+
+    #[test]
+    fn contains() {
+        let polygon = Polygon::new(vec![(0, 0), (4, 0), (4, 4), (2, 2), (0, 4)]);
+
+        assert_eq!(polygon.contains((1, 1)), Containment::Inside);
+        assert_eq!(polygon.contains((3, 2)), Containment::Inside);
+        assert_eq!(polygon.contains((2, 3)), Containment::Outside);
+        assert_eq!(polygon.contains((2, 2)), Containment::Boundary);
+        assert_eq!(polygon.contains((1, 2)), Containment::Inside);
+        assert_eq!(polygon.contains((4, 1)), Containment::Boundary);
+        assert_eq!(polygon.contains((5, 0)), Containment::Outside);
+        assert_eq!(polygon.contains((-1, 0)), Containment::Outside);
+        assert_eq!(polygon.contains_cell((3, 3)), Containment::Boundary);
+    }
+
+    #[test]
+    fn contains_agrees_with_pick() {
+        let polygon = Polygon::new(vec![(0, 0), (7, 0), (7, 3), (3, 3), (3, 6), (0, 6)]);
+        let (mut inside, mut boundary) = (0, 0);
+        for x in -1..9 {
+            for y in -1..8 {
+                match polygon.contains((x, y)) {
+                    Containment::Inside => inside += 1,
+                    Containment::Boundary => boundary += 1,
+                    Containment::Outside => {}
+                }
+            }
+        }
+        assert_eq!(inside, polygon.interior_points());
+        assert_eq!(boundary, polygon.boundary_points());
+    }
+
+    #[test]
+    fn degenerate() {
+        let empty = Polygon::new(vec![]);
+        assert_eq!(empty.double_area(), 0);
+        assert_eq!(empty.interior_points(), 0);
+
+        let point = Polygon::new(vec![(3, 3)]);
+        assert_eq!(point.interior_points(), 0);
+        assert_eq!(point.contains((3, 3)), Containment::Boundary);
+
+        let line = Polygon::new(vec![(0, 0), (4, 0)]);
+        assert_eq!(line.double_area(), 0);
+        assert_eq!(line.interior_points(), 0);
+        assert_eq!(
+            Polygon::new(vec![(0, 0), (2, 2), (4, 4)]).interior_points(),
+            0
+        );
+    }
+}
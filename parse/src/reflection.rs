@@ -0,0 +1,168 @@
+use crate::Grid;
+
+/// A line between two rows or columns that a grid can be mirrored across
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ReflectionAxis {
+    /// a vertical line just left of column `x`, so `x` columns are left of it
+    Vertical(usize),
+    /// a horizontal line just above row `y`, so `y` rows are above it
+    Horizontal(usize),
+}
+
+/// A candidate [`ReflectionAxis`] and how far the grid is from being mirrored across it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Reflection {
+    pub axis: ReflectionAxis,
+    /// number of mirrored pairs of cells that differ, cells with no partner are ignored
+    pub mismatches: usize,
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Counts the mirrored pairs that differ across `axis`, stopping early once more
+    /// than `limit` have been found.
+    fn count_mismatches(&self, axis: ReflectionAxis, limit: usize) -> usize {
+        let cell = |x: usize, y: usize| &self.data[self.index(x, y)];
+        let mut mismatches = 0;
+        match axis {
+            ReflectionAxis::Vertical(x) => {
+                for (left, right) in (0..x).rev().zip(x..self.width) {
+                    for y in 0..self.height {
+                        if cell(left, y) != cell(right, y) {
+                            mismatches += 1;
+                            if mismatches > limit {
+                                return mismatches;
+                            }
+                        }
+                    }
+                }
+            }
+            ReflectionAxis::Horizontal(y) => {
+                for (top, bottom) in (0..y).rev().zip(y..self.height) {
+                    for x in 0..self.width {
+                        if cell(x, top) != cell(x, bottom) {
+                            mismatches += 1;
+                            if mismatches > limit {
+                                return mismatches;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        mismatches
+    }
+
+    fn reflection_axes(&self) -> impl Iterator<Item = ReflectionAxis> {
+        (1..self.width)
+            .map(ReflectionAxis::Vertical)
+            .chain((1..self.height).map(ReflectionAxis::Horizontal))
+    }
+
+    /// Panics if `axis` is not strictly inside the grid.
+    pub fn reflection_mismatches(&self, axis: ReflectionAxis) -> usize {
+        let inside = match axis {
+            ReflectionAxis::Vertical(x) => (1..self.width).contains(&x),
+            ReflectionAxis::Horizontal(y) => (1..self.height).contains(&y),
+        };
+        assert!(inside, "{axis:?} is not inside the grid");
+        self.count_mismatches(axis, usize::MAX)
+    }
+
+    /// Every axis between two rows or columns, vertical axes first, with its mismatches.
+    pub fn reflections(&self) -> impl Iterator<Item = Reflection> + '_ {
+        self.reflection_axes().map(|axis| Reflection {
+            axis,
+            mismatches: self.count_mismatches(axis, usize::MAX),
+        })
+    }
+
+    /// Axes with exactly `mismatches` differing pairs, vertical axes first.
+    ///
+    /// `0` gives the perfect reflections, and `1` the ones that need a single cell
+    /// changed.
+    pub fn reflections_with_mismatches(
+        &self,
+        mismatches: usize,
+    ) -> impl Iterator<Item = ReflectionAxis> + '_ {
+        self.reflection_axes()
+            .filter(move |&axis| self.count_mismatches(axis, mismatches) == mismatches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_grid;
+    use indoc::indoc;
+
+    const EXAMPLE: &str = indoc! {r#"
+        #.##..##.
+        ..#.##.#.
+        ##......#
+        ##......#
+        ..#.##.#.
+        ..##..##.
+        #.#.##.#.
+
+        #...##..#
+        #....#..#
+        ..##..###
+        #####.##.
+        #####.##.
+        ..##..###
+        #....#..#
+    "#};
+
+    fn summarize(mismatches: usize) -> usize {
+        EXAMPLE
+            .split("\n\n")
+            .map(|pattern| {
+                let grid = parse_grid(pattern, |c| c).unwrap();
+                let axis = grid.reflections_with_mismatches(mismatches).next();
+                match axis {
+                    Some(ReflectionAxis::Vertical(x)) => x,
+                    Some(ReflectionAxis::Horizontal(y)) => 100 * y,
+                    None => panic!("no reflection in\n{pattern}"),
+                }
+            })
+            .sum()
+    }
+
+    #[test]
+    fn aoc2023_day13_example() {
+        assert_eq!(summarize(0), 405);
+        assert_eq!(summarize(1), 400);
+    }
+
+    #[test]
+    fn reflections_count_every_axis() {
+        let grid = parse_grid("abba\nabbc\n", |c| c).unwrap();
+        let reflections = grid
+            .reflections()
+            .map(|r| (r.axis, r.mismatches))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            reflections,
+            vec![
+                (ReflectionAxis::Vertical(1), 2),
+                (ReflectionAxis::Vertical(2), 1),
+                (ReflectionAxis::Vertical(3), 2),
+                (ReflectionAxis::Horizontal(1), 1),
+            ]
+        );
+        assert_eq!(grid.reflection_mismatches(ReflectionAxis::Vertical(2)), 1);
+        assert_eq!(
+            grid.reflections_with_mismatches(1).collect::<Vec<_>>(),
+            vec![ReflectionAxis::Vertical(2), ReflectionAxis::Horizontal(1)]
+        );
+        assert_eq!(grid.reflections_with_mismatches(0).next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "not inside the grid")]
+    fn reflection_mismatches_on_edge_panics() {
+        let grid = parse_grid("ab\ncd\n", |c| c).unwrap();
+        grid.reflection_mismatches(ReflectionAxis::Horizontal(2));
+    }
+}
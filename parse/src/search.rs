@@ -0,0 +1,591 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use petgraph::visit::{EdgeRef, GraphProp, IntoEdgeReferences, NodeIndexable};
+
+/// Interns states so the frontier can hold plain indices instead of requiring `S: Ord`.
+struct Arena<S, C> {
+    states: Vec<S>,
+    index: HashMap<S, usize>,
+    cost: Vec<C>,
+    parent: Vec<Option<usize>>,
+}
+
+impl<S, C> Arena<S, C>
+where
+    S: Clone + Hash + Eq,
+    C: Copy,
+{
+    fn new() -> Self {
+        Arena {
+            states: Vec::new(),
+            index: HashMap::new(),
+            cost: Vec::new(),
+            parent: Vec::new(),
+        }
+    }
+
+    fn get_or_insert(&mut self, state: S, cost: C) -> (usize, bool) {
+        if let Some(&i) = self.index.get(&state) {
+            return (i, false);
+        }
+        let i = self.states.len();
+        self.index.insert(state.clone(), i);
+        self.states.push(state);
+        self.cost.push(cost);
+        self.parent.push(None);
+        (i, true)
+    }
+
+    fn path(&self, mut i: usize) -> Vec<S> {
+        let mut path = vec![self.states[i].clone()];
+        while let Some(parent) = self.parent[i] {
+            path.push(self.states[parent].clone());
+            i = parent;
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// A* search from any of `starts` to the first state satisfying `goal`.
+///
+/// `heuristic` must never overestimate the remaining cost, otherwise the returned
+/// path is not guaranteed to be the cheapest.
+/// Returns the total cost and the path, including both the start and the goal.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+    mut heuristic: impl FnMut(&S) -> C,
+) -> Option<(C, Vec<S>)>
+where
+    S: Clone + Hash + Eq,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut arena = Arena::new();
+    let mut frontier = BinaryHeap::new();
+
+    for start in starts {
+        let h = heuristic(&start);
+        let (i, _) = arena.get_or_insert(start, C::default());
+        frontier.push(Reverse((h, C::default(), i)));
+    }
+
+    while let Some(Reverse((_, cost, i))) = frontier.pop() {
+        // stale entry, a cheaper route to this state was found after it was queued
+        if cost > arena.cost[i] {
+            continue;
+        }
+        let state = arena.states[i].clone();
+        if goal(&state) {
+            return Some((cost, arena.path(i)));
+        }
+
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            let h = heuristic(&next);
+            let (j, inserted) = arena.get_or_insert(next, next_cost);
+            if inserted || next_cost < arena.cost[j] {
+                arena.cost[j] = next_cost;
+                arena.parent[j] = Some(i);
+                frontier.push(Reverse((next_cost + h, next_cost, j)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Dijkstra's algorithm from any of `starts` to the first state satisfying `goal`.
+///
+/// Returns the total cost and the path, including both the start and the goal.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<(C, Vec<S>)>
+where
+    S: Clone + Hash + Eq,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, successors, goal, |_| C::default())
+}
+
+/// Breadth first search for graphs where every step costs one.
+///
+/// Returns the number of steps and the path, including both the start and the goal.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Option<(usize, Vec<S>)>
+where
+    S: Clone + Hash + Eq,
+    I: IntoIterator<Item = S>,
+{
+    let mut arena = Arena::new();
+    let mut frontier = VecDeque::new();
+
+    for start in starts {
+        let (i, inserted) = arena.get_or_insert(start, 0);
+        if inserted {
+            frontier.push_back(i);
+        }
+    }
+
+    while let Some(i) = frontier.pop_front() {
+        let state = arena.states[i].clone();
+        if goal(&state) {
+            return Some((arena.cost[i], arena.path(i)));
+        }
+
+        let steps = arena.cost[i] + 1;
+        for next in successors(&state) {
+            let (j, inserted) = arena.get_or_insert(next, steps);
+            if inserted {
+                arena.parent[j] = Some(i);
+                frontier.push_back(j);
+            }
+        }
+    }
+
+    None
+}
+
+/// Breadth first search for graphs where every step costs either zero or one.
+///
+/// Successors are yielded as `(state, weight)`, with `weight` being `0` or `1`.
+/// Returns the total cost and the path, including both the start and the goal.
+pub fn zero_one_bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Option<(usize, Vec<S>)>
+where
+    S: Clone + Hash + Eq,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut arena = Arena::new();
+    let mut frontier = VecDeque::new();
+
+    for start in starts {
+        let (i, _) = arena.get_or_insert(start, 0);
+        frontier.push_back((0, i));
+    }
+
+    while let Some((cost, i)) = frontier.pop_front() {
+        if cost > arena.cost[i] {
+            continue;
+        }
+        let state = arena.states[i].clone();
+        if goal(&state) {
+            return Some((cost, arena.path(i)));
+        }
+
+        for (next, weight) in successors(&state) {
+            debug_assert!(
+                weight <= 1,
+                "zero_one_bfs weights must be 0 or 1, got {weight}"
+            );
+            let next_cost = cost + weight;
+            let (j, inserted) = arena.get_or_insert(next, next_cost);
+            if inserted || next_cost < arena.cost[j] {
+                arena.cost[j] = next_cost;
+                arena.parent[j] = Some(i);
+                if weight == 0 {
+                    frontier.push_front((next_cost, j));
+                } else {
+                    frontier.push_back((next_cost, j));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Depth first search state for [`longest_path`], with nodes numbered by index.
+struct LongestPath {
+    /// `(neighbor, weight)` for every edge leaving each node
+    adjacency: Vec<Vec<(usize, u64)>>,
+    /// the heaviest edge entering each node, to bound what is left to gain
+    best_in: Vec<u64>,
+    goal: usize,
+    best: Option<u64>,
+    /// whether to keep the path, which [`longest_path_length`] does not need
+    record_path: bool,
+    path: Vec<usize>,
+    best_path: Vec<usize>,
+}
+
+impl LongestPath {
+    fn new<G>(graph: G, goal: usize, weight: impl Fn(&G::EdgeWeight) -> u64) -> Self
+    where
+        G: IntoEdgeReferences + NodeIndexable + GraphProp,
+    {
+        let nodes = graph.node_bound();
+        assert!(
+            nodes <= 64,
+            "longest_path supports at most 64 nodes, got {nodes}"
+        );
+
+        let mut adjacency = vec![Vec::new(); nodes];
+        let mut best_in = vec![0; nodes];
+        for edge in graph.edge_references() {
+            let (from, to, weight) = (
+                graph.to_index(edge.source()),
+                graph.to_index(edge.target()),
+                weight(edge.weight()),
+            );
+            adjacency[from].push((to, weight));
+            best_in[to] = best_in[to].max(weight);
+            if !graph.is_directed() {
+                adjacency[to].push((from, weight));
+                best_in[from] = best_in[from].max(weight);
+            }
+        }
+        // heavy edges first finds long paths early, which makes the bound prune more
+        for edges in &mut adjacency {
+            edges.sort_unstable_by_key(|&(_, weight)| Reverse(weight));
+        }
+
+        LongestPath {
+            adjacency,
+            best_in,
+            goal,
+            best: None,
+            record_path: false,
+            path: Vec::new(),
+            best_path: Vec::new(),
+        }
+    }
+
+    fn run(&mut self, start: usize) {
+        let remaining = self.best_in.iter().sum::<u64>() - self.best_in[start];
+        self.path = vec![start];
+        self.search(start, 1 << start, 0, remaining);
+    }
+
+    /// `remaining` is the sum of `best_in` over the unvisited nodes
+    fn search(&mut self, node: usize, visited: u64, length: u64, remaining: u64) {
+        if node == self.goal {
+            if Some(length) > self.best {
+                self.best = Some(length);
+                if self.record_path {
+                    self.best_path.clone_from(&self.path);
+                }
+            }
+            return;
+        }
+        if self.best.is_some_and(|best| length + remaining <= best) {
+            return;
+        }
+
+        for i in 0..self.adjacency[node].len() {
+            let (next, weight) = self.adjacency[node][i];
+            if visited & (1 << next) != 0 {
+                continue;
+            }
+            if self.record_path {
+                self.path.push(next);
+            }
+            self.search(
+                next,
+                visited | 1 << next,
+                length + weight,
+                remaining - self.best_in[next],
+            );
+            if self.record_path {
+                self.path.pop();
+            }
+        }
+    }
+}
+
+/// The heaviest path from `start` to `goal` that visits no node twice.
+///
+/// Exhaustive depth first search, so only for small graphs such as junction graphs,
+/// pruning branches that cannot beat the best path found so far even if they went
+/// through every remaining node on its heaviest edge. Works on any petgraph graph,
+/// such as `&Graph` or `&GraphMap`.
+/// Returns the total weight and the path, including both the start and the goal.
+///
+/// Panics if the graph has more than 64 nodes.
+pub fn longest_path<G>(
+    graph: G,
+    start: G::NodeId,
+    goal: G::NodeId,
+    weight: impl Fn(&G::EdgeWeight) -> u64,
+) -> Option<(u64, Vec<G::NodeId>)>
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp,
+{
+    let mut search = LongestPath::new(graph, graph.to_index(goal), weight);
+    search.record_path = true;
+    search.run(graph.to_index(start));
+
+    let best = search.best?;
+    Some((
+        best,
+        search
+            .best_path
+            .into_iter()
+            .map(|i| graph.from_index(i))
+            .collect(),
+    ))
+}
+
+/// Like [`longest_path`], but only the total weight, without keeping track of the path.
+pub fn longest_path_length<G>(
+    graph: G,
+    start: G::NodeId,
+    goal: G::NodeId,
+    weight: impl Fn(&G::EdgeWeight) -> u64,
+) -> Option<u64>
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp,
+{
+    let mut search = LongestPath::new(graph, graph.to_index(goal), weight);
+    search.run(graph.to_index(start));
+    search.best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_grid, Grid, Relationship};
+    use indoc::indoc;
+
+    fn maze() -> Grid<char> {
+        let input = indoc! {r#"
+            ###########
+            #....#....#
+            #....#....#
+            #....#....#
+            #.........#
+            ###########
+        "#};
+        parse_grid(input, |c| c).unwrap()
+    }
+
+    fn open_neighbors(grid: &Grid<char>, (x, y): (usize, usize)) -> Vec<(usize, usize)> {
+        grid.get_neighbors(x, y)
+            .unwrap()
+            .iter(&Relationship::Orthogonal)
+            .filter(|&(x, y)| *grid.get(x, y).unwrap() == '.')
+            .collect()
+    }
+
+    #[test]
+    fn bfs_finds_shortest_path() {
+        let grid = maze();
+        let (steps, path) = bfs(
+            [(1, 1)],
+            |&coord| open_neighbors(&grid, coord),
+            |&coord| coord == (9, 1),
+        )
+        .expect("path exists");
+
+        assert_eq!(steps, 14);
+        assert_eq!(path.len(), 15);
+        assert_eq!(path.first(), Some(&(1, 1)));
+        assert_eq!(path.last(), Some(&(9, 1)));
+        for (a, b) in path.iter().zip(path.iter().skip(1)) {
+            assert_eq!(a.0.abs_diff(b.0) + a.1.abs_diff(b.1), 1);
+        }
+    }
+
+    #[test]
+    fn bfs_unreachable() {
+        let grid = maze();
+        assert_eq!(
+            bfs(
+                [(1, 1)],
+                |&coord| open_neighbors(&grid, coord),
+                |&coord| coord == (0, 0),
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn dijkstra_and_astar_agree_with_bfs() {
+        let grid = maze();
+        let successors =
+            |&coord: &(usize, usize)| open_neighbors(&grid, coord).into_iter().map(|n| (n, 1u64));
+
+        let (dijkstra_cost, _) = dijkstra([(1, 1)], successors, |&c| c == (9, 1)).unwrap();
+        let (astar_cost, path) = astar(
+            [(1, 1)],
+            successors,
+            |&c| c == (9, 1),
+            |&(x, y)| (x.abs_diff(9) + y.abs_diff(1)) as u64,
+        )
+        .unwrap();
+
+        assert_eq!(dijkstra_cost, 14);
+        assert_eq!(astar_cost, 14);
+        assert_eq!(path.len(), 15);
+    }
+
+    #[test]
+    fn dijkstra_prefers_cheap_detour() {
+        // a -> c directly costs 10, a -> b -> c costs 3
+        let edges = |s: &char| match s {
+            'a' => vec![('c', 10), ('b', 1)],
+            'b' => vec![('c', 2)],
+            _ => vec![],
+        };
+        assert_eq!(
+            dijkstra(['a'], edges, |&s| s == 'c'),
+            Some((3, vec!['a', 'b', 'c']))
+        );
+    }
+
+    #[test]
+    fn zero_one_bfs_uses_free_edges() {
+        // moving right is free, moving down costs one
+        let (cost, path) = zero_one_bfs(
+            [(0u8, 0u8)],
+            |&(x, y)| {
+                let mut next = vec![];
+                if x < 5 {
+                    next.push(((x + 1, y), 0));
+                }
+                if y < 5 {
+                    next.push(((x, y + 1), 1));
+                }
+                next
+            },
+            |&c| c == (5, 5),
+        )
+        .unwrap();
+
+        assert_eq!(cost, 5);
+        assert_eq!(path.len(), 11);
+    }
+
+    #[test]
+    fn multiple_starts() {
+        let (steps, path) = bfs([0, 7], |&n: &i32| [n - 1, n + 1], |&n| n == 5).unwrap();
+        assert_eq!(steps, 2);
+        assert_eq!(path, vec![7, 6, 5]);
+    }
+
+    #[test]
+    fn aoc2023_day17_example() {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        struct Node {
+            coord: (usize, usize),
+            direction: (isize, isize),
+            steps_in_direction: u64,
+        }
+
+        let input = indoc! {r#"
+            2413432311323
+            3215453535623
+            3255245654254
+            3446585845452
+            4546657867536
+            1438598798454
+            4457876987766
+            3637877979653
+            4654967986887
+            4564679986453
+            1224686865563
+            2546548887735
+            4322674655533
+        "#};
+        let grid = parse_grid(input, |c| u64::from(c.to_digit(10).unwrap())).unwrap();
+        let goal = (grid.width - 1, grid.height - 1);
+
+        let starts = [(0, 1), (1, 0)].map(|direction| Node {
+            coord: (0, 0),
+            direction,
+            steps_in_direction: 0,
+        });
+
+        let (cost, path) = dijkstra(
+            starts,
+            |node| {
+                [(0, -1), (0, 1), (-1, 0), (1, 0)]
+                    .into_iter()
+                    .filter(|&d| d != (-node.direction.0, -node.direction.1))
+                    .filter(|&d| d != node.direction || node.steps_in_direction < 3)
+                    .filter_map(|d| {
+                        let coord = grid.raycast_from(node.coord, d).next()?;
+                        let next = Node {
+                            coord,
+                            direction: d,
+                            steps_in_direction: if d == node.direction {
+                                node.steps_in_direction + 1
+                            } else {
+                                1
+                            },
+                        };
+                        Some((next, *grid.get_tuple(coord).unwrap()))
+                    })
+                    .collect::<Vec<_>>()
+            },
+            |node| node.coord == goal,
+        )
+        .unwrap();
+
+        assert_eq!(cost, 102);
+        assert_eq!(path.last().unwrap().coord, goal);
+    }
+
+    #[test]
+    fn longest_path_prefers_the_detour() {
+        let mut graph = petgraph::Graph::<char, u64, petgraph::Undirected>::new_undirected();
+        let [a, b, c, d, e] = ['a', 'b', 'c', 'd', 'e'].map(|name| graph.add_node(name));
+        graph.extend_with_edges([
+            (a, b, 1),
+            (b, e, 1),
+            (b, c, 5),
+            (c, d, 5),
+            (d, e, 1),
+            (a, c, 2),
+        ]);
+
+        let (length, path) = longest_path(&graph, a, e, |&w| w).unwrap();
+        assert_eq!(length, 12);
+        assert_eq!(path.iter().map(|&n| graph[n]).collect::<String>(), "abcde");
+        assert_eq!(longest_path(&graph, a, a, |&w| w), Some((0, vec![a])));
+        assert_eq!(longest_path_length(&graph, a, e, |&w| w), Some(12));
+    }
+
+    #[test]
+    fn longest_path_respects_direction() {
+        let mut graph = petgraph::Graph::<(), u64>::new();
+        let [a, b, c] = [(); 3].map(|()| graph.add_node(()));
+        graph.extend_with_edges([(a, b, 1), (c, b, 10), (b, c, 1)]);
+
+        assert_eq!(longest_path(&graph, a, c, |&w| w), Some((2, vec![a, b, c])));
+        assert_eq!(longest_path(&graph, c, a, |&w| w), None);
+    }
+
+    #[test]
+    fn longest_path_on_graph_map() {
+        let grid = parse_grid("..#\n...\n#..\n", |c| c).unwrap();
+        let graph = grid.build_graph::<u64, petgraph::Undirected>(
+            &Relationship::Orthogonal,
+            |(from, _), (to, _)| (from == '.' && to == '.').then_some(1),
+        );
+
+        let (length, path) = longest_path(&graph, (0, 0), (2, 2), |&w| w).unwrap();
+        assert_eq!(length, 4);
+        assert_eq!(path.len(), 5);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(2, 2)));
+        assert_eq!(longest_path_length(&graph, (0, 0), (2, 2), |&w| w), Some(4));
+        assert_eq!(longest_path_length(&graph, (0, 0), (2, 0), |&w| w), None);
+    }
+}
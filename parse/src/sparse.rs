@@ -0,0 +1,398 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    fmt::Debug,
+    hash::Hash,
+};
+
+use crate::{Grid, Relationship};
+
+/// Inclusive bounding box of the occupied cells in a [`SparseGrid`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    pub min: (i64, i64),
+    pub max: (i64, i64),
+}
+
+impl Bounds {
+    fn point((x, y): (i64, i64)) -> Self {
+        Bounds {
+            min: (x, y),
+            max: (x, y),
+        }
+    }
+
+    fn include(&mut self, (x, y): (i64, i64)) {
+        self.min = (self.min.0.min(x), self.min.1.min(y));
+        self.max = (self.max.0.max(x), self.max.1.max(y));
+    }
+
+    fn on_edge(&self, (x, y): (i64, i64)) -> bool {
+        x == self.min.0 || x == self.max.0 || y == self.min.1 || y == self.max.1
+    }
+
+    #[must_use]
+    pub fn width(&self) -> usize {
+        (self.max.0 - self.min.0 + 1) as usize
+    }
+
+    #[must_use]
+    pub fn height(&self) -> usize {
+        (self.max.1 - self.min.1 + 1) as usize
+    }
+
+    #[must_use]
+    pub fn contains(&self, (x, y): (i64, i64)) -> bool {
+        (self.min.0..=self.max.0).contains(&x) && (self.min.1..=self.max.1).contains(&y)
+    }
+}
+
+/// An unbounded grid with signed coordinates that only stores occupied cells.
+///
+/// x is the column, y is the row, and the bounding box grows as cells are set.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<(i64, i64), T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `None` while the grid is empty
+    #[must_use]
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    #[must_use]
+    pub fn get(&self, x: i64, y: i64) -> Option<&T> {
+        self.cells.get(&(x, y))
+    }
+
+    pub fn get_mut(&mut self, x: i64, y: i64) -> Option<&mut T> {
+        self.cells.get_mut(&(x, y))
+    }
+
+    #[must_use]
+    pub fn get_tuple(&self, (x, y): (i64, i64)) -> Option<&T> {
+        self.get(x, y)
+    }
+
+    #[must_use]
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        self.cells.contains_key(&(x, y))
+    }
+
+    /// returns the previous value, if any
+    pub fn set(&mut self, x: i64, y: i64, value: T) -> Option<T> {
+        match &mut self.bounds {
+            Some(bounds) => bounds.include((x, y)),
+            None => self.bounds = Some(Bounds::point((x, y))),
+        }
+        self.cells.insert((x, y), value)
+    }
+
+    /// inserts the result of `default` if the cell is empty
+    pub fn entry_or_insert_with(&mut self, x: i64, y: i64, default: impl FnOnce() -> T) -> &mut T {
+        match self.cells.entry((x, y)) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                match &mut self.bounds {
+                    Some(bounds) => bounds.include((x, y)),
+                    None => self.bounds = Some(Bounds::point((x, y))),
+                }
+                entry.insert(default())
+            }
+        }
+    }
+
+    /// shrinks the bounding box if the removed cell was on its edge
+    pub fn remove(&mut self, x: i64, y: i64) -> Option<T> {
+        let removed = self.cells.remove(&(x, y))?;
+        if self.bounds.is_some_and(|bounds| bounds.on_edge((x, y))) {
+            self.bounds = self.cells.keys().fold(None, |acc, &coord| match acc {
+                None => Some(Bounds::point(coord)),
+                Some(mut bounds) => {
+                    bounds.include(coord);
+                    Some(bounds)
+                }
+            });
+        }
+        Some(removed)
+    }
+
+    /// occupied cells, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = ((i64, i64), &T)> {
+        self.cells.iter().map(|(&coord, t)| (coord, t))
+    }
+
+    #[must_use]
+    pub fn build_lookup(&self) -> HashMap<T, Vec<(i64, i64)>>
+    where
+        T: Eq + Hash + Copy,
+    {
+        self.iter().fold(HashMap::new(), |mut acc, (coord, t)| {
+            acc.entry(*t).or_default().push(coord);
+            acc
+        })
+    }
+
+    pub fn lookup(&self, value: T) -> Vec<(i64, i64)>
+    where
+        T: Eq,
+    {
+        self.lookup_filter(|t| *t == value)
+    }
+
+    pub fn lookup_filter(&self, predicate: impl Fn(&T) -> bool) -> Vec<(i64, i64)> {
+        self.iter()
+            .filter(|(_, t)| predicate(t))
+            .map(|(coord, _)| coord)
+            .collect()
+    }
+
+    /// every neighbor coordinate, occupied or not, in the order of [`Relationship::offsets`]
+    pub fn neighbors(
+        &self,
+        (x, y): (i64, i64),
+        relation: &Relationship,
+    ) -> impl Iterator<Item = (i64, i64)> {
+        relation
+            .offsets()
+            .iter()
+            .map(move |&(dx, dy)| (x + dx as i64, y + dy as i64))
+    }
+
+    /// only the neighbors that hold a value
+    pub fn occupied_neighbors<'a>(
+        &'a self,
+        coord: (i64, i64),
+        relation: &Relationship,
+    ) -> impl Iterator<Item = ((i64, i64), &'a T)> + 'a {
+        self.neighbors(coord, relation)
+            .filter_map(|n| self.get_tuple(n).map(|t| (n, t)))
+    }
+
+    pub fn map<V>(&self, fun: impl Fn(((i64, i64), &T)) -> V) -> SparseGrid<V> {
+        SparseGrid {
+            cells: self
+                .iter()
+                .map(|(coord, t)| (coord, fun((coord, t))))
+                .collect(),
+            bounds: self.bounds,
+        }
+    }
+
+    /// A dense copy of the bounding box, with empty cells set to `fill`.
+    ///
+    /// The minimum corner of the bounds becomes `(0, 0)` in the returned grid.
+    #[must_use]
+    pub fn to_grid(&self, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let Some(bounds) = self.bounds else {
+            return Grid {
+                data: Vec::new(),
+                width: 0,
+                height: 0,
+            };
+        };
+
+        let mut grid = Grid {
+            data: vec![fill; bounds.width() * bounds.height()],
+            width: bounds.width(),
+            height: bounds.height(),
+        };
+        for ((x, y), t) in self.iter() {
+            grid.set(
+                (x - bounds.min.0) as usize,
+                (y - bounds.min.1) as usize,
+                t.clone(),
+            );
+        }
+        grid
+    }
+}
+
+impl<T> FromIterator<((i64, i64), T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = ((i64, i64), T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        for ((x, y), t) in iter {
+            grid.set(x, y, t);
+        }
+        grid
+    }
+}
+
+impl<T> From<Grid<T>> for SparseGrid<T> {
+    fn from(grid: Grid<T>) -> Self {
+        let width = grid.width;
+        grid.data
+            .into_iter()
+            .enumerate()
+            .map(|(i, t)| (((i % width) as i64, (i / width) as i64), t))
+            .collect()
+    }
+}
+
+impl<T: Debug> Debug for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some(bounds) = self.bounds else {
+            return write!(f, "width=0, height=0 {{\n}}");
+        };
+
+        writeln!(
+            f,
+            "width={}, height={}, min=({}, {}) {{",
+            bounds.width(),
+            bounds.height(),
+            bounds.min.0,
+            bounds.min.1
+        )?;
+
+        let dbg_str = self
+            .cells
+            .iter()
+            .map(|(&coord, tile)| (coord, format!("{tile:?}")))
+            .collect::<HashMap<_, _>>();
+
+        let max_len = dbg_str.values().map(String::len).max().unwrap_or(0);
+
+        for y in bounds.min.1..=bounds.max.1 {
+            let row = (bounds.min.0..=bounds.max.0)
+                .map(|x| {
+                    let s = dbg_str.get(&(x, y)).map_or("", String::as_str);
+                    format!("{s:max_len$}")
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+            writeln!(f, " {y}\t| {row}")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_grid;
+    use indoc::indoc;
+
+    #[test]
+    fn set_tracks_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+
+        grid.set(0, 0, 'a');
+        grid.set(-3, 2, 'b');
+        grid.set(4, -1, 'c');
+
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds {
+                min: (-3, -1),
+                max: (4, 2)
+            })
+        );
+        assert_eq!(grid.get(-3, 2), Some(&'b'));
+        assert_eq!(grid.get(1, 1), None);
+        assert_eq!(grid.len(), 3);
+    }
+
+    #[test]
+    fn remove_shrinks_bounds() {
+        let mut grid: SparseGrid<char> = [((0, 0), 'a'), ((5, 5), 'b'), ((2, 3), 'c')]
+            .into_iter()
+            .collect();
+
+        assert_eq!(grid.remove(2, 3), Some('c'));
+        assert_eq!(grid.bounds().unwrap().max, (5, 5));
+
+        assert_eq!(grid.remove(5, 5), Some('b'));
+        assert_eq!(grid.bounds().unwrap().max, (0, 0));
+
+        assert_eq!(grid.remove(5, 5), None);
+        grid.remove(0, 0);
+        assert_eq!(grid.bounds(), None);
+    }
+
+    #[test]
+    fn neighbors() {
+        let mut grid = SparseGrid::new();
+        grid.set(0, -1, '#');
+        grid.set(1, 1, '#');
+
+        assert_eq!(
+            grid.neighbors((0, 0), &Relationship::Orthogonal)
+                .collect::<Vec<_>>(),
+            vec![(0, -1), (0, 1), (-1, 0), (1, 0)]
+        );
+        assert_eq!(
+            grid.occupied_neighbors((0, 0), &Relationship::Adjacent)
+                .collect::<Vec<_>>(),
+            vec![((0, -1), &'#'), ((1, 1), &'#')]
+        );
+    }
+
+    #[test]
+    fn round_trips_dense_grid() {
+        let input = indoc! {r#"
+            abc
+            def
+        "#};
+        let dense = parse_grid(input, |c| c).unwrap();
+        let sparse = SparseGrid::from(dense.clone());
+
+        assert_eq!(sparse.len(), 6);
+        assert_eq!(sparse.get(2, 1), Some(&'f'));
+        assert_eq!(sparse.to_grid('.'), dense);
+        assert_eq!(sparse.lookup('e'), vec![(1, 1)]);
+    }
+
+    #[test]
+    fn to_grid_fills_gaps() {
+        let mut grid = SparseGrid::new();
+        grid.set(-1, -1, '#');
+        grid.set(1, 0, '#');
+
+        let dense = grid.to_grid('.');
+        assert_eq!(dense.width, 3);
+        assert_eq!(dense.height, 2);
+        assert_eq!(dense.data, vec!['#', '.', '.', '.', '.', '#']);
+    }
+
+    #[test]
+    fn debug() {
+        let mut grid = SparseGrid::new();
+        grid.set(-1, -1, 'a');
+        grid.set(1, 0, 'b');
+
+        assert_eq!(
+            format!("{grid:?}"),
+            "width=3, height=2, min=(-1, -1) {\n -1\t| 'a'        \n 0\t|         'b'\n}"
+        );
+    }
+}
@@ -0,0 +1,224 @@
+use std::borrow::Cow;
+
+use crate::{Grid, Relationship};
+
+/// A set of `(dx, dy)` offsets describing which cells count as neighbors.
+///
+/// Neighbors are always produced in the order of [`Stencil::offsets`]. The built in
+/// shapes list their offsets in reading order, top row first and left to right within a
+/// row, while a [`Relationship`] keeps the order of [`Relationship::offsets`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Stencil {
+    offsets: Cow<'static, [(isize, isize)]>,
+}
+
+impl Stencil {
+    /// Any offsets, used as given, in the given order.
+    pub fn new(offsets: impl IntoIterator<Item = (isize, isize)>) -> Self {
+        Stencil {
+            offsets: offsets.into_iter().collect(),
+        }
+    }
+
+    /// the offsets in reading order within a square of `radius` that satisfy `keep`,
+    /// leaving out `(0, 0)`
+    fn square(radius: usize, keep: impl Fn(isize, isize) -> bool) -> Self {
+        let radius = radius as isize;
+        Stencil::new(
+            (-radius..=radius)
+                .flat_map(|dy| (-radius..=radius).map(move |dx| (dx, dy)))
+                .filter(|&(dx, dy)| (dx, dy) != (0, 0) && keep(dx, dy)),
+        )
+    }
+
+    /// Every cell at most `radius` orthogonal steps away, a diamond.
+    #[must_use]
+    pub fn manhattan(radius: usize) -> Self {
+        Stencil::square(radius, |dx, dy| {
+            dx.unsigned_abs() + dy.unsigned_abs() <= radius
+        })
+    }
+
+    /// Every cell at most `radius` king moves away, a square.
+    #[must_use]
+    pub fn chebyshev(radius: usize) -> Self {
+        Stencil::square(radius, |_, _| true)
+    }
+
+    /// The eight moves of a chess knight.
+    #[must_use]
+    pub fn knight() -> Self {
+        Stencil::square(2, |dx, dy| dx.abs() * dy.abs() == 2)
+    }
+
+    #[must_use]
+    pub fn offsets(&self) -> &[(isize, isize)] {
+        &self.offsets
+    }
+
+    /// The neighbors of `(x, y)` inside a `width` by `height` grid.
+    pub fn neighbors(
+        &self,
+        (x, y): (usize, usize),
+        (width, height): (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets.iter().filter_map(move |&(dx, dy)| {
+            let (x, y) = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+            (x < width && y < height).then_some((x, y))
+        })
+    }
+
+    /// The neighbors of `(x, y)` in a `width` by `height` grid whose opposite edges are
+    /// joined, so every offset gives a neighbor.
+    pub fn neighbors_wrapping(
+        &self,
+        (x, y): (usize, usize),
+        (width, height): (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets.iter().map(move |&(dx, dy)| {
+            (
+                (x as isize + dx).rem_euclid(width as isize) as usize,
+                (y as isize + dy).rem_euclid(height as isize) as usize,
+            )
+        })
+    }
+}
+
+impl From<&Relationship> for Stencil {
+    fn from(relationship: &Relationship) -> Self {
+        Stencil {
+            offsets: Cow::Borrowed(relationship.offsets()),
+        }
+    }
+}
+
+impl From<&Stencil> for Stencil {
+    fn from(stencil: &Stencil) -> Self {
+        stencil.clone()
+    }
+}
+
+impl<T> Grid<T> {
+    /// Neighbors of `(x, y)` by `stencil` that are inside the grid.
+    pub fn stencil_neighbors<'a>(
+        &self,
+        x: usize,
+        y: usize,
+        stencil: &'a Stencil,
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        stencil.neighbors((x, y), (self.width, self.height))
+    }
+
+    /// Neighbors of `(x, y)` by `stencil`, wrapping around the edges of the grid.
+    pub fn stencil_neighbors_wrapping<'a>(
+        &self,
+        x: usize,
+        y: usize,
+        stencil: &'a Stencil,
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        stencil.neighbors_wrapping((x, y), (self.width, self.height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_grid;
+    use petgraph::Undirected;
+
+    #[test]
+    fn shapes() {
+        assert_eq!(
+            Stencil::manhattan(1),
+            Stencil::new([(0, -1), (-1, 0), (1, 0), (0, 1)])
+        );
+        assert_eq!(Stencil::manhattan(2).offsets().len(), 12);
+        assert_eq!(Stencil::chebyshev(1).offsets().len(), 8);
+        assert_eq!(Stencil::chebyshev(2).offsets().len(), 24);
+        assert_eq!(
+            Stencil::knight().offsets(),
+            &[
+                (-1, -2),
+                (1, -2),
+                (-2, -1),
+                (2, -1),
+                (-2, 1),
+                (2, 1),
+                (-1, 2),
+                (1, 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn relationship_keeps_its_order() {
+        let grid = Grid {
+            data: vec![(); 9],
+            width: 3,
+            height: 3,
+        };
+        for relationship in [
+            Relationship::Orthogonal,
+            Relationship::Diagonal,
+            Relationship::Adjacent,
+        ] {
+            let stencil = Stencil::from(&relationship);
+            assert_eq!(
+                grid.stencil_neighbors(1, 1, &stencil).collect::<Vec<_>>(),
+                grid.get_neighbors(1, 1)
+                    .unwrap()
+                    .iter(&relationship)
+                    .collect::<Vec<_>>()
+            );
+            assert_eq!(
+                grid.stencil_neighbors_wrapping(0, 0, &stencil)
+                    .collect::<Vec<_>>(),
+                grid.get_neighbors_wrapping(0, 0)
+                    .unwrap()
+                    .iter(&relationship)
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn knight_neighbors() {
+        let grid = Grid {
+            data: vec![(); 4 * 3],
+            width: 4,
+            height: 3,
+        };
+        let knight = Stencil::knight();
+        assert_eq!(
+            grid.stencil_neighbors(0, 0, &knight).collect::<Vec<_>>(),
+            vec![(2, 1), (1, 2)]
+        );
+        assert_eq!(grid.stencil_neighbors_wrapping(0, 0, &knight).count(), 8);
+        assert!(grid
+            .stencil_neighbors_wrapping(0, 0, &knight)
+            .any(|coord| coord == (3, 1)));
+    }
+
+    #[test]
+    fn build_graph_with_relationship() {
+        let grid = parse_grid("...\n.#.\n...\n", |c| c).unwrap();
+        let graph = grid.build_graph::<(), Undirected>(&Relationship::Orthogonal, |_, _| Some(()));
+        assert_eq!(graph.edge_count(), 12);
+        assert_eq!(graph.neighbors((1, 1)).count(), 4);
+    }
+
+    #[test]
+    fn build_graph_with_stencil() {
+        let grid = parse_grid("...\n.#.\n...\n", |c| c).unwrap();
+        let graph = grid.build_graph::<(), Undirected>(Stencil::knight(), |(from, _), (to, _)| {
+            (from == '.' && to == '.').then_some(())
+        });
+        // the knight tours the eight outer cells in a single cycle
+        assert_eq!(graph.edge_count(), 8);
+        assert_eq!(
+            graph.neighbors((0, 0)).collect::<Vec<_>>(),
+            vec![(2, 1), (1, 2)]
+        );
+        assert_eq!(graph.neighbors((1, 1)).count(), 0);
+    }
+}
@@ -0,0 +1,109 @@
+/// One of the 8 ways to rotate or reflect a rectangle onto itself
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Symmetry {
+    Identity,
+    RotateCw,
+    Rotate180,
+    RotateCcw,
+    /// mirror left to right, reversing each row
+    FlipHorizontal,
+    /// mirror top to bottom, reversing the order of the rows
+    FlipVertical,
+    /// mirror along the main diagonal, swapping x and y
+    Transpose,
+    /// mirror along the other diagonal
+    AntiTranspose,
+}
+
+impl Symmetry {
+    pub const ALL: [Symmetry; 8] = [
+        Symmetry::Identity,
+        Symmetry::RotateCw,
+        Symmetry::Rotate180,
+        Symmetry::RotateCcw,
+        Symmetry::FlipHorizontal,
+        Symmetry::FlipVertical,
+        Symmetry::Transpose,
+        Symmetry::AntiTranspose,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Symmetry> {
+        Symmetry::ALL.into_iter()
+    }
+
+    /// whether the width and height trade places
+    #[must_use]
+    pub fn swaps_axes(self) -> bool {
+        matches!(
+            self,
+            Symmetry::RotateCw
+                | Symmetry::RotateCcw
+                | Symmetry::Transpose
+                | Symmetry::AntiTranspose
+        )
+    }
+
+    #[must_use]
+    pub fn inverse(self) -> Symmetry {
+        match self {
+            Symmetry::RotateCw => Symmetry::RotateCcw,
+            Symmetry::RotateCcw => Symmetry::RotateCw,
+            other => other,
+        }
+    }
+
+    /// the `(width, height)` of a `width` by `height` rectangle after the transform
+    #[must_use]
+    pub fn dimensions(self, (width, height): (usize, usize)) -> (usize, usize) {
+        if self.swaps_axes() {
+            (height, width)
+        } else {
+            (width, height)
+        }
+    }
+
+    /// Where `(x, y)` in a `width` by `height` rectangle ends up after the transform.
+    #[must_use]
+    pub fn apply(self, (x, y): (usize, usize), (width, height): (usize, usize)) -> (usize, usize) {
+        let (right, bottom) = (width - 1 - x, height - 1 - y);
+        match self {
+            Symmetry::Identity => (x, y),
+            Symmetry::RotateCw => (bottom, x),
+            Symmetry::Rotate180 => (right, bottom),
+            Symmetry::RotateCcw => (y, right),
+            Symmetry::FlipHorizontal => (right, y),
+            Symmetry::FlipVertical => (x, bottom),
+            Symmetry::Transpose => (y, x),
+            Symmetry::AntiTranspose => (bottom, right),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inverse_undoes_apply() {
+        let size = (4, 3);
+        for symmetry in Symmetry::iter() {
+            let transformed = symmetry.dimensions(size);
+            for x in 0..size.0 {
+                for y in 0..size.1 {
+                    let moved = symmetry.apply((x, y), size);
+                    assert!(moved.0 < transformed.0 && moved.1 < transformed.1);
+                    assert_eq!(symmetry.inverse().apply(moved, transformed), (x, y));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn corners() {
+        let size = (3, 2);
+        assert_eq!(Symmetry::RotateCw.apply((0, 0), size), (1, 0));
+        assert_eq!(Symmetry::RotateCcw.apply((0, 0), size), (0, 2));
+        assert_eq!(Symmetry::AntiTranspose.apply((0, 0), size), (1, 2));
+        assert_eq!(Symmetry::FlipHorizontal.apply((0, 1), size), (2, 1));
+    }
+}
@@ -0,0 +1,299 @@
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display, Write as _},
+    io::IsTerminal,
+};
+
+use crate::{Grid, Overlay, Point, Rgb};
+
+/// glyph used for highlighted cells when colours are off
+const PLAIN_HIGHLIGHT: char = '*';
+
+/// How a single cell is drawn in the terminal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Style {
+    pub glyph: char,
+    pub foreground: Option<Rgb>,
+    pub background: Option<Rgb>,
+}
+
+impl Style {
+    #[must_use]
+    pub fn plain(glyph: char) -> Self {
+        Style {
+            glyph,
+            foreground: None,
+            background: None,
+        }
+    }
+
+    /// A plain glyph taken from the `Debug` output, without the quotes around a `char`.
+    #[must_use]
+    pub fn from_debug<V: Debug + ?Sized>(value: &V) -> Self {
+        let text = format!("{value:?}");
+        let inner = text
+            .strip_prefix('\'')
+            .and_then(|text| text.strip_suffix('\''))
+            .unwrap_or(&text);
+        let inner = inner
+            .strip_prefix('\\')
+            .filter(|escaped| escaped.chars().count() == 1)
+            .unwrap_or(inner);
+        Style::plain(inner.chars().next().unwrap_or(' '))
+    }
+
+    #[must_use]
+    pub fn fg(self, colour: Rgb) -> Self {
+        Style {
+            foreground: Some(colour),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn bg(self, colour: Rgb) -> Self {
+        Style {
+            background: Some(colour),
+            ..self
+        }
+    }
+}
+
+impl From<char> for Style {
+    fn from(glyph: char) -> Self {
+        Style::plain(glyph)
+    }
+}
+
+/// A grid ready to print, built by [`Grid::render`] and drawn through [`Display`]
+pub struct Render<'a, T, F> {
+    grid: &'a Grid<T>,
+    style: F,
+    overlays: Vec<Overlay>,
+    viewport: Option<(Point, usize)>,
+    margin: usize,
+    colour: bool,
+}
+
+/// whether stdout can show ANSI colours
+fn colour_supported() -> bool {
+    std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none()
+}
+
+impl<T, F: Fn(&T) -> Style> Render<'_, T, F> {
+    /// Draws the overlay's cells with its colour as their background, or as
+    /// [`PLAIN_HIGHLIGHT`] without colours. Later overlays win.
+    #[must_use]
+    pub fn overlay(mut self, overlay: Overlay) -> Self {
+        self.overlays.push(overlay);
+        self
+    }
+
+    /// Only draws the cells at most `radius` rows and columns away from `center`.
+    #[must_use]
+    pub fn viewport(mut self, center: impl Into<Point>, radius: usize) -> Self {
+        self.viewport = Some((center.into(), radius));
+        self
+    }
+
+    /// Makes the row number column at least `margin` characters wide, to line the rows up
+    /// with another grid that has more of them.
+    #[must_use]
+    pub fn margin(mut self, margin: usize) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Overrides the default, which is to use colours only when stdout is a terminal.
+    #[must_use]
+    pub fn colour(mut self, colour: bool) -> Self {
+        self.colour = colour;
+        self
+    }
+
+    pub fn print(&self) {
+        println!("{self}");
+    }
+
+    /// the half open column and row ranges that are drawn
+    fn bounds(&self) -> ((usize, usize), (usize, usize)) {
+        let (width, height) = (self.grid.width, self.grid.height);
+        match self.viewport {
+            None => ((0, width), (0, height)),
+            Some((center, radius)) => (
+                (
+                    center.x.saturating_sub(radius).min(width),
+                    center.x.saturating_add(radius + 1).min(width),
+                ),
+                (
+                    center.y.saturating_sub(radius).min(height),
+                    center.y.saturating_add(radius + 1).min(height),
+                ),
+            ),
+        }
+    }
+}
+
+impl<T, F: Fn(&T) -> Style> Display for Render<'_, T, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ((left, right), (top, bottom)) = self.bounds();
+        let highlights = self
+            .overlays
+            .iter()
+            .flat_map(|overlay| {
+                overlay
+                    .cells()
+                    .into_iter()
+                    .map(|cell| (cell, overlay.colour()))
+            })
+            .collect::<HashMap<_, _>>();
+
+        let margin = bottom.saturating_sub(1).to_string().len().max(self.margin);
+        let digits = right.saturating_sub(1).to_string().len();
+
+        // column numbers written downwards, one digit per line
+        for place in (0..digits).rev() {
+            write!(f, "{:margin$}   ", "")?;
+            for x in left..right {
+                let digit = x / 10usize.pow(place as u32) % 10;
+                if place > 0 && x < 10usize.pow(place as u32) {
+                    f.write_char(' ')?;
+                } else {
+                    write!(f, "{digit}")?;
+                }
+            }
+            writeln!(f)?;
+        }
+
+        for y in top..bottom {
+            write!(f, "{y:>margin$} | ")?;
+            for x in left..right {
+                let mut style = (self.style)(&self.grid.data[self.grid.index(x, y)]);
+                let highlight = highlights.get(&Point::new(x, y));
+                if !self.colour {
+                    let glyph = highlight.map_or(style.glyph, |_| PLAIN_HIGHLIGHT);
+                    f.write_char(glyph)?;
+                    continue;
+                }
+
+                if let Some(&colour) = highlight {
+                    style.background = Some(colour);
+                }
+                if let Some(Rgb { r, g, b }) = style.foreground {
+                    write!(f, "\x1b[38;2;{r};{g};{b}m")?;
+                }
+                if let Some(Rgb { r, g, b }) = style.background {
+                    write!(f, "\x1b[48;2;{r};{g};{b}m")?;
+                }
+                f.write_char(style.glyph)?;
+                if style.foreground.is_some() || style.background.is_some() {
+                    f.write_str("\x1b[0m")?;
+                }
+            }
+            if y + 1 < bottom {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> Grid<T> {
+    /// A terminal picture of the grid with row and column numbers, drawing each cell with
+    /// `style`. Colours are used when stdout is a terminal and `NO_COLOR` is unset.
+    pub fn render<F: Fn(&T) -> Style>(&self, style: F) -> Render<'_, T, F> {
+        Render {
+            grid: self,
+            style,
+            overlays: Vec::new(),
+            viewport: None,
+            margin: 0,
+            colour: colour_supported(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_grid;
+    use indoc::indoc;
+
+    fn example() -> Grid<char> {
+        parse_grid(
+            indoc! {r#"
+                ...#........
+                .#.....#....
+                ..........#.
+            "#},
+            |c| c,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn plain() {
+        let grid = example();
+        let rendered = grid
+            .render(|&c| Style::plain(c))
+            .overlay(Overlay::path([(0, 0), (0, 2)], Rgb::RED))
+            .colour(false)
+            .to_string();
+        assert_eq!(
+            rendered,
+            indoc! {r#"
+                              11
+                    012345678901
+                0 | *..#........
+                1 | *#.....#....
+                2 | *.........#."#}
+        );
+    }
+
+    #[test]
+    fn viewport() {
+        let grid = example();
+        let rendered = grid
+            .render(|&c| c.into())
+            .viewport((10, 1), 1)
+            .colour(false)
+            .to_string();
+        assert_eq!(
+            rendered,
+            indoc! {r#"
+                     11
+                    901
+                0 | ...
+                1 | ...
+                2 | .#."#}
+        );
+
+        let corner = grid.render(|&c| c.into()).viewport((0, 0), 1).colour(false);
+        assert_eq!(corner.to_string(), "    01\n0 | ..\n1 | .#");
+    }
+
+    #[test]
+    fn from_debug() {
+        assert_eq!(Style::from_debug(&'#').glyph, '#');
+        assert_eq!(Style::from_debug(&'\\').glyph, '\\');
+        assert_eq!(Style::from_debug(&7).glyph, '7');
+        assert_eq!(Style::from_debug("ab").glyph, '"');
+    }
+
+    #[test]
+    fn ansi() {
+        let grid = parse_grid(".#\n", |c| c).unwrap();
+        let rendered = grid
+            .render(|&c| match c {
+                '#' => Style::plain(c).fg(Rgb::RED),
+                _ => Style::plain(c),
+            })
+            .overlay(Overlay::highlight([(0, 0)], Rgb::BLUE))
+            .colour(true)
+            .to_string();
+        assert_eq!(
+            rendered,
+            "    01\n0 | \x1b[48;2;0;0;255m.\x1b[0m\x1b[38;2;255;0;0m#\x1b[0m"
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use std::fmt::Debug;
+
+use crate::{Grid, Relationship};
+
+/// An infinite plane made of copies of a [`Grid`] laid edge to edge.
+///
+/// Cells are addressed with signed `(x, y)` coordinates, where the base grid
+/// occupies `0..width` and `0..height`, which is tile `(0, 0)`. The base grid must
+/// not be empty.
+#[derive(Clone, Copy)]
+pub struct TiledGrid<'a, T> {
+    grid: &'a Grid<T>,
+}
+
+impl<'a, T> TiledGrid<'a, T> {
+    /// panics if `grid` has no width or no height, as an empty grid can't tile the plane
+    #[must_use]
+    pub fn new(grid: &'a Grid<T>) -> Self {
+        assert!(
+            grid.width > 0 && grid.height > 0,
+            "can't tile an empty {}x{} grid",
+            grid.width,
+            grid.height
+        );
+        TiledGrid { grid }
+    }
+
+    #[must_use]
+    pub fn base_grid(&self) -> &'a Grid<T> {
+        self.grid
+    }
+
+    /// the coordinate in the base grid that `(x, y)` is a copy of
+    #[must_use]
+    pub fn base(&self, (x, y): (i64, i64)) -> (usize, usize) {
+        (
+            x.rem_euclid(self.grid.width as i64) as usize,
+            y.rem_euclid(self.grid.height as i64) as usize,
+        )
+    }
+
+    /// which copy of the grid `(x, y)` is in, `(0, 0)` is the base grid
+    #[must_use]
+    pub fn tile(&self, (x, y): (i64, i64)) -> (i64, i64) {
+        (
+            x.div_euclid(self.grid.width as i64),
+            y.div_euclid(self.grid.height as i64),
+        )
+    }
+
+    #[must_use]
+    pub fn split(&self, coord: (i64, i64)) -> ((i64, i64), (usize, usize)) {
+        (self.tile(coord), self.base(coord))
+    }
+
+    /// inverse of [`TiledGrid::split`]
+    #[must_use]
+    pub fn join(&self, (tile_x, tile_y): (i64, i64), (x, y): (usize, usize)) -> (i64, i64) {
+        (
+            tile_x * self.grid.width as i64 + x as i64,
+            tile_y * self.grid.height as i64 + y as i64,
+        )
+    }
+
+    #[must_use]
+    pub fn get(&self, coord: (i64, i64)) -> &'a T {
+        let (x, y) = self.base(coord);
+        &self.grid.data[self.grid.index(x, y)]
+    }
+
+    /// neighbors in the order of [`Relationship::offsets`], crossing into other tiles as needed
+    pub fn neighbors(
+        &self,
+        (x, y): (i64, i64),
+        relation: &Relationship,
+    ) -> impl Iterator<Item = (i64, i64)> {
+        relation
+            .offsets()
+            .iter()
+            .map(move |&(dx, dy)| (x + dx as i64, y + dy as i64))
+    }
+}
+
+impl<T: Debug> Debug for TiledGrid<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TiledGrid of {:?}", self.grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_grid;
+    use indoc::indoc;
+
+    fn grid() -> Grid<char> {
+        let input = indoc! {r#"
+            abc
+            def
+        "#};
+        parse_grid(input, |c| c).unwrap()
+    }
+
+    #[test]
+    fn base_and_tile() {
+        let grid = grid();
+        let tiled = grid.tiled();
+
+        assert_eq!(tiled.split((0, 0)), ((0, 0), (0, 0)));
+        assert_eq!(tiled.split((2, 1)), ((0, 0), (2, 1)));
+        assert_eq!(tiled.split((3, 2)), ((1, 1), (0, 0)));
+        assert_eq!(tiled.split((-1, -1)), ((-1, -1), (2, 1)));
+        assert_eq!(tiled.split((-3, -2)), ((-1, -1), (0, 0)));
+        assert_eq!(tiled.split((-4, 5)), ((-2, 2), (2, 1)));
+
+        assert_eq!(tiled.get((-1, 0)), &'c');
+        assert_eq!(tiled.get((4, -1)), &'e');
+    }
+
+    #[test]
+    fn join_inverts_split() {
+        let grid = grid();
+        let tiled = grid.tiled();
+
+        for x in -10..10 {
+            for y in -10..10 {
+                let (tile, base) = tiled.split((x, y));
+                assert_eq!(tiled.join(tile, base), (x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn neighbors_cross_tile_borders() {
+        let grid = grid();
+        let tiled = grid.tiled();
+
+        assert_eq!(
+            tiled
+                .neighbors((0, 0), &Relationship::Orthogonal)
+                .collect::<Vec<_>>(),
+            vec![(0, -1), (0, 1), (-1, 0), (1, 0)]
+        );
+
+        let wrapped = grid
+            .get_neighbors_wrapping(0, 0)
+            .unwrap()
+            .iter(&Relationship::Adjacent)
+            .collect::<Vec<_>>();
+        let tiled_bases = tiled
+            .neighbors((0, 0), &Relationship::Adjacent)
+            .map(|coord| tiled.base(coord))
+            .collect::<Vec<_>>();
+        assert_eq!(tiled_bases, wrapped);
+    }
+
+    #[test]
+    #[should_panic(expected = "can't tile an empty 0x0 grid")]
+    fn empty_grid_is_rejected() {
+        let grid: Grid<char> = Grid {
+            data: vec![],
+            width: 0,
+            height: 0,
+        };
+        grid.tiled();
+    }
+}
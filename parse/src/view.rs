@@ -0,0 +1,323 @@
+use std::{
+    fmt::Debug,
+    iter::{StepBy, Take},
+    slice,
+};
+
+use miette::Result;
+
+use crate::{grid::Axis, Grid, GridError, Point};
+
+/// A borrowed column of a [`Grid`] or [`SubGrid`], read with a stride instead of copied
+#[derive(Clone, Copy)]
+pub struct Column<'a, T> {
+    /// starts at the first cell of the column
+    data: &'a [T],
+    stride: usize,
+    len: usize,
+}
+
+impl<'a, T> Column<'a, T> {
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[must_use]
+    pub fn get(&self, y: usize) -> Option<&'a T> {
+        (y < self.len).then(|| &self.data[y * self.stride])
+    }
+
+    pub fn iter(&self) -> Take<StepBy<slice::Iter<'a, T>>> {
+        self.data.iter().step_by(self.stride).take(self.len)
+    }
+}
+
+impl<'a, T> IntoIterator for Column<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Take<StepBy<slice::Iter<'a, T>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: PartialEq> PartialEq for Column<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for Column<'_, T> {}
+
+impl<T: Debug> Debug for Column<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// A borrowed rectangle inside a [`Grid`], addressed from its own top left corner
+#[derive(Clone, Copy)]
+pub struct SubGrid<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Point,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl<'a, T> SubGrid<'a, T> {
+    /// where the top left corner is in the underlying grid
+    #[must_use]
+    pub fn origin(&self) -> Point {
+        self.origin
+    }
+
+    #[must_use]
+    pub fn base_grid(&self) -> &'a Grid<T> {
+        self.grid
+    }
+
+    pub fn validate(&self, x: usize, y: usize) -> Result<(), GridError> {
+        let axis = if x >= self.width {
+            (x, Axis::X)
+        } else if y >= self.height {
+            (y, Axis::Y)
+        } else {
+            return Ok(());
+        };
+        Err(GridError::BoundsError {
+            index: axis.0,
+            width: self.width,
+            height: self.height,
+            axis: axis.1,
+        })
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Result<&'a T> {
+        self.validate(x, y)?;
+        Ok(self.unchecked_get(x, y))
+    }
+
+    fn unchecked_get(&self, x: usize, y: usize) -> &'a T {
+        &self.grid.data[self.grid.index(self.origin.x + x, self.origin.y + y)]
+    }
+
+    pub fn get_tuple(&self, (x, y): (usize, usize)) -> Result<&'a T> {
+        self.get(x, y)
+    }
+
+    pub fn get_at(&self, point: Point) -> Result<&'a T> {
+        self.get(point.x, point.y)
+    }
+
+    /// Panics if `y` is outside the view.
+    #[must_use]
+    pub fn row(&self, y: usize) -> &'a [T] {
+        assert!(y < self.height, "row {y} is outside the view");
+        let start = self.grid.index(self.origin.x, self.origin.y + y);
+        &self.grid.data[start..start + self.width]
+    }
+
+    /// Panics if `x` is outside the view.
+    #[must_use]
+    pub fn col(&self, x: usize) -> Column<'a, T> {
+        assert!(x < self.width, "column {x} is outside the view");
+        let start = self.grid.index(self.origin.x + x, self.origin.y);
+        Column {
+            // an empty view can start past the end of the data
+            data: self.grid.data.get(start..).unwrap_or_default(),
+            stride: self.grid.width,
+            len: self.height,
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + '_ {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = Column<'a, T>> + '_ {
+        (0..self.width).map(|x| self.col(x))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &'a T)> + '_ {
+        self.rows()
+            .enumerate()
+            .flat_map(|(y, row)| row.iter().enumerate().map(move |(x, t)| ((x, y), t)))
+    }
+
+    /// A smaller view, with `(x, y)` relative to this one.
+    ///
+    /// Panics if the rectangle does not fit inside this view.
+    #[must_use]
+    pub fn subgrid(&self, x: usize, y: usize, width: usize, height: usize) -> SubGrid<'a, T> {
+        assert!(
+            x + width <= self.width && y + height <= self.height,
+            "{width}x{height} at ({x}, {y}) does not fit in {}x{}",
+            self.width,
+            self.height
+        );
+        SubGrid {
+            grid: self.grid,
+            origin: Point::new(self.origin.x + x, self.origin.y + y),
+            width,
+            height,
+        }
+    }
+
+    #[must_use]
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            data: self.rows().flatten().cloned().collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for SubGrid<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.width == other.width && self.height == other.height && self.rows().eq(other.rows())
+    }
+}
+
+impl<T: Eq> Eq for SubGrid<'_, T> {}
+
+impl<T: Debug + Clone> Debug for SubGrid<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SubGrid at {:?} of {:?}", self.origin, self.to_grid())
+    }
+}
+
+impl<T> Grid<T> {
+    /// Panics if `y` is outside the grid.
+    #[must_use]
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {y} is outside the grid");
+        &self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Panics if `y` is outside the grid.
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        assert!(y < self.height, "row {y} is outside the grid");
+        &mut self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Panics if `x` is outside the grid.
+    #[must_use]
+    pub fn col(&self, x: usize) -> Column<'_, T> {
+        self.view().col(x)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        // chunks of a width 0 grid would panic, and there are no cells to hand out anyway
+        let height = if self.width == 0 { 0 } else { self.height };
+        self.data.chunks_exact_mut(self.width.max(1)).take(height)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = Column<'_, T>> {
+        (0..self.width).map(|x| self.col(x))
+    }
+
+    /// the whole grid as a [`SubGrid`]
+    #[must_use]
+    pub fn view(&self) -> SubGrid<'_, T> {
+        SubGrid {
+            grid: self,
+            origin: Point::new(0, 0),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// The `width` by `height` rectangle with its top left corner at `(x, y)`.
+    ///
+    /// Panics if the rectangle does not fit inside the grid.
+    #[must_use]
+    pub fn subgrid(&self, x: usize, y: usize, width: usize, height: usize) -> SubGrid<'_, T> {
+        self.view().subgrid(x, y, width, height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_grid;
+    use indoc::indoc;
+
+    fn example() -> Grid<char> {
+        parse_grid(
+            indoc! {r#"
+                abcd
+                efgh
+                ijkl
+            "#},
+            |c| c,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = example();
+        assert_eq!(grid.row(1), &['e', 'f', 'g', 'h']);
+        assert_eq!(grid.col(2).iter().collect::<String>(), "cgk");
+        assert_eq!(grid.col(2).get(1), Some(&'g'));
+        assert_eq!(grid.col(2).get(3), None);
+        assert_eq!(grid.col(3).iter().rev().collect::<String>(), "lhd");
+        assert_eq!(grid.columns().count(), 4);
+        assert_eq!(grid.rows().count(), 3);
+        assert_ne!(grid.col(0), grid.col(1));
+        assert_eq!(format!("{:?}", grid.col(0)), "['a', 'e', 'i']");
+    }
+
+    #[test]
+    fn row_mut() {
+        let mut grid = example();
+        grid.row_mut(2).reverse();
+        assert_eq!(grid.row(2), &['l', 'k', 'j', 'i']);
+
+        for row in grid.rows_mut() {
+            row[0] = '#';
+        }
+        assert_eq!(grid.col(0).iter().collect::<String>(), "###");
+    }
+
+    #[test]
+    fn subgrid() {
+        let grid = example();
+        let view = grid.subgrid(1, 1, 3, 2);
+        assert_eq!((view.width, view.height), (3, 2));
+        assert_eq!(view.get(0, 0).unwrap(), &'f');
+        assert_eq!(view.get_at(Point::new(2, 1)).unwrap(), &'l');
+        assert!(view.get(3, 0).is_err());
+        assert!(view.get(0, 2).is_err());
+        assert_eq!(view.row(1), &['j', 'k', 'l']);
+        assert_eq!(view.col(1).iter().collect::<String>(), "gk");
+        assert_eq!(view.iter().map(|(_, c)| c).collect::<String>(), "fghjkl");
+
+        let inner = view.subgrid(1, 0, 2, 2);
+        assert_eq!(inner.origin(), Point::new(2, 1));
+        assert_eq!(inner.to_grid(), parse_grid("gh\nkl\n", |c| c).unwrap());
+        assert_eq!(inner, grid.subgrid(2, 1, 2, 2));
+        assert_eq!(grid.view().to_grid(), grid);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit")]
+    fn subgrid_out_of_bounds_panics() {
+        let grid = example();
+        let _ = grid.subgrid(2, 0, 3, 1);
+    }
+}
@@ -0,0 +1,146 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use miette::Result;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// A day of the puzzle, registered with the `aoc` runner.
+///
+/// The input is parsed once and both parts borrow the parsed value.
+pub trait Solution {
+    const DAY: u8;
+    /// the puzzle input used when no other path is given
+    const INPUT: &'static str;
+    /// the parts that have a puzzle, which is both except on the last day
+    const PARTS: &'static [Part] = &[Part::One, Part::Two];
+
+    type Parsed<'a>;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>>;
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Self::Answer1>;
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Self::Answer2>;
+}
+
+/// The answer to one part, with how long it took
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timed {
+    pub answer: String,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+impl Timed {
+    pub fn total(&self) -> Duration {
+        self.parse + self.solve
+    }
+}
+
+/// An object safe view of a [`Solution`], so that every day can be held in one list
+pub trait Registered: Sync {
+    fn day(&self) -> u8;
+    fn input(&self) -> &'static str;
+    fn parts(&self) -> &'static [Part];
+    fn run(&self, input: &str, part: Part) -> Result<Timed>;
+}
+
+impl<S: Solution + Sync> Registered for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn input(&self) -> &'static str {
+        S::INPUT
+    }
+
+    fn parts(&self) -> &'static [Part] {
+        S::PARTS
+    }
+
+    fn run(&self, input: &str, part: Part) -> Result<Timed> {
+        let start = Instant::now();
+        let parsed = S::parse(input)?;
+        let parse = start.elapsed();
+
+        let start = Instant::now();
+        let answer = match part {
+            Part::One => S::part1(&parsed)?.to_string(),
+            Part::Two => S::part2(&parsed)?.to_string(),
+        };
+        let solve = start.elapsed();
+
+        Ok(Timed {
+            answer,
+            parse,
+            solve,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use miette::miette;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 1;
+        const INPUT: &'static str = "1 2 3";
+        const PARTS: &'static [Part] = &[Part::One];
+
+        type Parsed<'a> = Vec<i64>;
+        type Answer1 = i64;
+        type Answer2 = i64;
+
+        fn parse(input: &str) -> Result<Vec<i64>> {
+            input
+                .split_whitespace()
+                .map(|n| n.parse().map_err(|e| miette!("bad number {n:?}: {e}")))
+                .collect()
+        }
+
+        fn part1(parsed: &Vec<i64>) -> Result<i64> {
+            Ok(parsed.iter().sum())
+        }
+
+        fn part2(_parsed: &Vec<i64>) -> Result<i64> {
+            Err(miette!("there is no part 2"))
+        }
+    }
+
+    #[test]
+    fn registered_runs_through_solution() {
+        let registered: &dyn Registered = &Sum;
+        assert_eq!(registered.day(), 1);
+        assert_eq!(registered.parts(), &[Part::One]);
+
+        let timed = registered.run(registered.input(), Part::One).unwrap();
+        assert_eq!(timed.answer, "6");
+        assert_eq!(timed.total(), timed.parse + timed.solve);
+    }
+
+    #[test]
+    fn registered_reports_errors() {
+        let registered: &dyn Registered = &Sum;
+        assert!(registered.run("1 x", Part::One).is_err());
+        assert!(registered.run("1", Part::Two).is_err());
+    }
+}
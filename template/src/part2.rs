@@ -2,11 +2,6 @@ use miette::Result;
 use miette_pretty::Pretty;
 use parse::{Grid, QuickRegex, Tile};
 
-fn main() {
-    let input = include_str!("../input.txt");
-    dbg!(part2(input).unwrap());
-}
-
 fn parse(input: &str) -> Result<Vec<&str>> {
     input.lines().map(|l| Ok(l)).collect()
 }
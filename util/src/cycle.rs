@@ -0,0 +1,232 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// The shape of an eventually periodic sequence `x0, f(x0), f(f(x0)), ...`.
+///
+/// The first `prefix` states are never revisited, after which the sequence repeats
+/// every `period` states.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// Maps iteration `n` to the earliest iteration with the same state.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.period
+        }
+    }
+
+    /// Returns the state after `n` steps, replaying at most `prefix + period` steps.
+    pub fn fast_forward<S>(&self, initial: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+        (0..self.reduce(n)).fold(initial, |state, _| step(&state))
+    }
+}
+
+/// Floyd's tortoise and hare, keeping only two states alive at a time.
+///
+/// Never returns if the sequence does not eventually repeat.
+pub fn floyd<S: PartialEq + Clone>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    let mut prefix = 0;
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    let mut period = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        period += 1;
+    }
+
+    Cycle { prefix, period }
+}
+
+/// Brent's algorithm, which needs fewer calls to `step` than [`floyd`].
+///
+/// Never returns if the sequence does not eventually repeat.
+pub fn brent<S: PartialEq + Clone>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    tortoise = initial.clone();
+    hare = (0..period).fold(initial, |state, _| step(&state));
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    Cycle { prefix, period }
+}
+
+/// Every state visited before the sequence repeated, along with its [`Cycle`].
+#[derive(Debug, Clone)]
+pub struct History<S> {
+    pub cycle: Cycle,
+    pub states: Vec<S>,
+}
+
+impl<S> History<S> {
+    /// The state after `n` steps, without calling the step function again.
+    pub fn state_at(&self, n: usize) -> &S {
+        &self.states[self.cycle.reduce(n)]
+    }
+}
+
+/// Detects the cycle by remembering every state in a hash map.
+///
+/// Uses more memory than [`floyd`] or [`brent`], but only calls `step` once per
+/// distinct state and keeps the states around for [`History::state_at`].
+pub fn hashing<S: Hash + Eq + Clone>(initial: S, mut step: impl FnMut(&S) -> S) -> History<S> {
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = initial;
+
+    loop {
+        if let Some(&prefix) = seen.get(&state) {
+            return History {
+                cycle: Cycle {
+                    prefix,
+                    period: states.len() - prefix,
+                },
+                states,
+            };
+        }
+        let next = step(&state);
+        seen.insert(state.clone(), states.len());
+        states.push(state);
+        state = next;
+    }
+}
+
+/// Returns the state after `n` steps, skipping ahead once a repeat is found.
+///
+/// Stops early without looking for a cycle if `n` is reached first.
+pub fn nth_state<S: Hash + Eq + Clone>(initial: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = initial;
+
+    for i in 0..n {
+        if let Some(&prefix) = seen.get(&state) {
+            let cycle = Cycle {
+                prefix,
+                period: i - prefix,
+            };
+            return states.swap_remove(cycle.reduce(n));
+        }
+        let next = step(&state);
+        seen.insert(state.clone(), i);
+        states.push(state);
+        state = next;
+    }
+
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> 3 -> ...
+    fn rho(x: &u64) -> u64 {
+        if *x == 6 {
+            3
+        } else {
+            x + 1
+        }
+    }
+
+    #[test]
+    fn algorithms_agree_on_rho() {
+        let expected = Cycle {
+            prefix: 3,
+            period: 4,
+        };
+        assert_eq!(floyd(0, rho), expected);
+        assert_eq!(brent(0, rho), expected);
+        assert_eq!(hashing(0, rho).cycle, expected);
+    }
+
+    #[test]
+    fn algorithms_agree_on_pure_cycle() {
+        let step = |x: &u64| (x + 1) % 5;
+        let expected = Cycle {
+            prefix: 0,
+            period: 5,
+        };
+        assert_eq!(floyd(0, step), expected);
+        assert_eq!(brent(0, step), expected);
+        assert_eq!(hashing(0, step).cycle, expected);
+    }
+
+    #[test]
+    fn algorithms_agree_on_fixed_point() {
+        let step = |x: &u64| (*x).max(4) - 1;
+        let expected = Cycle {
+            prefix: 7,
+            period: 1,
+        };
+        assert_eq!(floyd(10, step), expected);
+        assert_eq!(brent(10, step), expected);
+        assert_eq!(hashing(10, step).cycle, expected);
+    }
+
+    #[test]
+    fn algorithms_agree_on_lcg() {
+        let step = |x: &u64| (x * x + 1) % 1_009;
+        for initial in 0..50 {
+            let history = hashing(initial, step);
+            assert_eq!(floyd(initial, step), history.cycle);
+            assert_eq!(brent(initial, step), history.cycle);
+        }
+    }
+
+    #[test]
+    fn fast_forward_matches_replay() {
+        let step = |x: &u64| (x * x + 1) % 1_009;
+        let history = hashing(2, step);
+        for n in [0, 1, 5, 37, 100, 1_000] {
+            let replayed = (0..n).fold(2, |x, _| step(&x));
+            assert_eq!(*history.state_at(n), replayed);
+            assert_eq!(history.cycle.fast_forward(2, step, n), replayed);
+            assert_eq!(nth_state(2, step, n), replayed);
+        }
+    }
+
+    #[test]
+    fn nth_state_huge_iteration_count() {
+        assert_eq!(
+            nth_state(0, rho, 1_000_000_000),
+            3 + (1_000_000_000 - 3) % 4
+        );
+        assert_eq!(nth_state(0, rho, 2), 2);
+    }
+}
@@ -1,3 +1,11 @@
+pub mod cycle;
+mod range_box;
+mod range_map;
 mod range_set;
+mod range_set_union;
 
+pub use range_box::RangeBox;
+pub use range_map::RangeMap;
+pub use range_set::RangeBound;
 pub use range_set::RangeSet;
+pub use range_set_union::RangeSetUnion;
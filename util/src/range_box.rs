@@ -0,0 +1,171 @@
+use std::fmt::{Debug, Display};
+
+use crate::{RangeBound, RangeSet};
+
+/// An axis-aligned box of integers, one closed [`RangeSet`] per dimension
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RangeBox<const N: usize, T = u64> {
+    ranges: [RangeSet<T>; N],
+}
+
+impl<const N: usize, T: RangeBound> RangeBox<N, T> {
+    pub fn new(ranges: [RangeSet<T>; N]) -> Self {
+        RangeBox { ranges }
+    }
+
+    pub fn ranges(&self) -> &[RangeSet<T>; N] {
+        &self.ranges
+    }
+
+    pub fn axis(&self, axis: usize) -> RangeSet<T> {
+        self.ranges[axis]
+    }
+
+    pub fn with_axis(&self, axis: usize, range: RangeSet<T>) -> Self {
+        let mut ranges = self.ranges;
+        ranges[axis] = range;
+        RangeBox { ranges }
+    }
+
+    /// number of integer points in the box
+    pub fn volume(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::ONE, |acc, range| acc * range.len())
+    }
+
+    pub fn contains(&self, point: [T; N]) -> bool {
+        self.ranges
+            .iter()
+            .zip(point)
+            .all(|(range, value)| range.contains(value))
+    }
+
+    pub fn intersection(&self, other: &RangeBox<N, T>) -> Option<RangeBox<N, T>> {
+        let mut ranges = self.ranges;
+        for (range, other) in ranges.iter_mut().zip(other.ranges) {
+            *range = (*range & other)?;
+        }
+        Some(RangeBox { ranges })
+    }
+
+    /// Splits along `axis` into the parts below `value` and at or above `value`.
+    pub fn split(&self, axis: usize, value: T) -> (Option<RangeBox<N, T>>, Option<RangeBox<N, T>>) {
+        let range = self.ranges[axis];
+        let below = (value > T::MIN)
+            .then(|| range & RangeSet::new(T::MIN, value - T::ONE))
+            .flatten();
+        let above = range & RangeSet::new(value, T::MAX);
+        (
+            below.map(|below| self.with_axis(axis, below)),
+            above.map(|above| self.with_axis(axis, above)),
+        )
+    }
+
+    /// The parts of `self` outside of `other`, as at most `2 * N` disjoint boxes.
+    pub fn difference(&self, other: &RangeBox<N, T>) -> Vec<RangeBox<N, T>> {
+        let Some(overlap) = self.intersection(other) else {
+            return vec![*self];
+        };
+
+        let mut result = Vec::new();
+        let mut rest = *self;
+        for axis in 0..N {
+            let overlap_range = overlap.axis(axis);
+            let (below, middle) = rest.split(axis, overlap_range.start());
+            result.extend(below);
+            let middle = middle.expect("overlap is inside self");
+            if overlap_range.end() < T::MAX {
+                let (middle, above) = middle.split(axis, overlap_range.end() + T::ONE);
+                result.extend(above);
+                rest = middle.expect("overlap is inside self");
+            } else {
+                rest = middle;
+            }
+        }
+        result
+    }
+}
+
+impl<const N: usize, T: Display> Debug for RangeBox<N, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RangeBox")?;
+        for range in &self.ranges {
+            write!(f, "{range}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cube(from: i64, to: i64) -> RangeBox<3, i64> {
+        RangeBox::new([RangeSet::new(from, to); 3])
+    }
+
+    #[test]
+    fn volume_and_contains() {
+        let parts = RangeBox::<4>::new([(1, 4000).into(); 4]);
+        assert_eq!(parts.volume(), 4000u64.pow(4));
+        assert!(parts.contains([1, 2, 3, 4000]));
+        assert!(!parts.contains([0, 2, 3, 4]));
+        assert_eq!(
+            format!("{:?}", cube(-1, 1)),
+            "RangeBox[-1, 1][-1, 1][-1, 1]"
+        );
+    }
+
+    #[test]
+    fn split() {
+        let b = cube(0, 9);
+        let (below, above) = b.split(1, 4);
+        assert_eq!(below.unwrap().axis(1), RangeSet::new(0, 3));
+        assert_eq!(above.unwrap().axis(1), RangeSet::new(4, 9));
+        assert_eq!(
+            below.unwrap().volume() + above.unwrap().volume(),
+            b.volume()
+        );
+
+        assert_eq!(b.split(0, 0), (None, Some(b)));
+        assert_eq!(b.split(0, 10), (Some(b), None));
+        assert_eq!(b.split(0, 20), (Some(b), None));
+        assert_eq!(b.split(0, -5), (None, Some(b)));
+    }
+
+    #[test]
+    fn intersection() {
+        assert_eq!(cube(0, 9).intersection(&cube(5, 20)), Some(cube(5, 9)));
+        assert_eq!(cube(0, 9).intersection(&cube(10, 20)), None);
+    }
+
+    #[test]
+    fn difference_matches_brute_force() {
+        let a = cube(0, 5);
+        let b = RangeBox::new([
+            RangeSet::new(2, 3),
+            RangeSet::new(-1, 2),
+            RangeSet::new(4, 10),
+        ]);
+        let parts = a.difference(&b);
+
+        assert_eq!(
+            parts.iter().map(RangeBox::volume).sum::<i64>(),
+            a.volume() - a.intersection(&b).unwrap().volume()
+        );
+        for x in -1..7 {
+            for y in -1..7 {
+                for z in -1..7 {
+                    let p = [x, y, z];
+                    let count = parts.iter().filter(|part| part.contains(p)).count();
+                    let expected = usize::from(a.contains(p) && !b.contains(p));
+                    assert_eq!(count, expected);
+                }
+            }
+        }
+
+        assert_eq!(a.difference(&cube(10, 20)), vec![a]);
+        assert_eq!(a.difference(&cube(-1, 6)), vec![]);
+    }
+}
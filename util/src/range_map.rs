@@ -0,0 +1,426 @@
+use std::{
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+};
+
+use crate::{RangeBound, RangeSet, RangeSetUnion};
+
+/// A piecewise translation of integers.
+///
+/// Each segment sends its source range onto a same-length range starting at its
+/// destination, and any value outside every segment maps to itself.
+///
+/// Two maps are equal when they send every value to the same place, whatever ranges
+/// were inserted to build them.
+#[derive(Clone)]
+pub struct RangeMap<T = u64> {
+    /// `(source, destination start)`, sorted and disjoint, never the identity
+    segments: Vec<(RangeSet<T>, T)>,
+    /// every source given to [`RangeMap::insert`], identity pieces included
+    inserted: RangeSetUnion<T>,
+}
+
+impl<T: PartialEq> PartialEq for RangeMap<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.segments == other.segments
+    }
+}
+
+impl<T: Eq> Eq for RangeMap<T> {}
+
+impl<T: Hash> Hash for RangeMap<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.segments.hash(state);
+    }
+}
+
+/// where `value` lands when `from` is moved to `to`
+fn shift<T: RangeBound>(value: T, from: T, to: T) -> T {
+    // identity pieces can span most of the domain, where `value - from` would overflow
+    if from == to {
+        value
+    } else {
+        to + (value - from)
+    }
+}
+
+/// whether `source` moved to start at `dest` still ends at or below `T::MAX`
+fn fits<T: RangeBound>(source: RangeSet<T>, dest: T) -> bool {
+    if dest <= source.start() {
+        // moving down, so the end only gets smaller and stays above `dest`
+        return true;
+    }
+    // the end moves up by `delta` and has `room` left below `T::MAX`, either of which can
+    // be too large for `T` when it is signed
+    let delta = dest.checked_sub(source.start());
+    let room = T::MAX.checked_sub(source.end());
+    match (delta, room) {
+        (Some(delta), Some(room)) => delta <= room,
+        (Some(_), None) => true,
+        (None, Some(_)) => false,
+        // `start < 0 <= dest` and `end < 0`, where neither side can overflow
+        (None, None) => dest + source.end() <= T::MAX + source.start(),
+    }
+}
+
+/// shifts the part `part` of `source` so that `source` starts at `dest`
+fn translate<T: RangeBound>(part: RangeSet<T>, source: RangeSet<T>, dest: T) -> RangeSet<T> {
+    RangeSet::new(
+        shift(part.start(), source.start(), dest),
+        shift(part.end(), source.start(), dest),
+    )
+}
+
+impl<T: RangeBound> Default for RangeMap<T> {
+    fn default() -> Self {
+        RangeMap {
+            segments: Vec::new(),
+            inserted: RangeSetUnion::new(),
+        }
+    }
+}
+
+impl<T: RangeBound> RangeMap<T> {
+    /// the identity map
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a map from pieces sorted by source, dropping identity pieces and merging
+    /// pieces that continue each other in both source and destination.
+    fn from_sorted_pieces(pieces: impl IntoIterator<Item = (RangeSet<T>, T)>) -> Self {
+        let mut segments: Vec<(RangeSet<T>, T)> = Vec::new();
+        for (source, dest) in pieces {
+            if dest == source.start() {
+                continue;
+            }
+            if let Some((last, last_dest)) = segments.last_mut() {
+                let last_dest_end = translate(*last, *last, *last_dest).end();
+                if last.end() + T::ONE == source.start()
+                    && dest > last_dest_end
+                    && dest - last_dest_end == T::ONE
+                {
+                    *last = RangeSet::new(last.start(), source.end());
+                    continue;
+                }
+            }
+            segments.push((source, dest));
+        }
+        let inserted = segments.iter().map(|&(source, _)| source).collect();
+        RangeMap { segments, inserted }
+    }
+
+    /// Sends `source` onto the range of the same length starting at `dest`.
+    ///
+    /// Panics if `source` overlaps a range already inserted, even one that maps to
+    /// itself, or if the destination would end past `T::MAX`.
+    pub fn insert(&mut self, source: impl Into<RangeSet<T>>, dest: T) {
+        let source = source.into();
+        if let Some(range) = self
+            .inserted
+            .ranges()
+            .iter()
+            .find(|range| (*range & source).is_some())
+        {
+            panic!("{source} overlaps {range}, which is already mapped");
+        }
+        assert!(fits(source, dest), "{source} moved to {dest} overflows");
+
+        let i = self
+            .segments
+            .partition_point(|(range, _)| range.end() < source.start());
+        self.segments.insert(i, (source, dest));
+        let mut inserted = std::mem::take(&mut self.inserted);
+        inserted.insert(source);
+        *self = Self::from_sorted_pieces(std::mem::take(&mut self.segments));
+        self.inserted = inserted;
+    }
+
+    /// the non-identity segments as `(source, destination start)`, in increasing order
+    pub fn segments(&self) -> &[(RangeSet<T>, T)] {
+        &self.segments
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    pub fn get(&self, value: T) -> T {
+        let i = self
+            .segments
+            .partition_point(|(range, _)| range.end() < value);
+        match self.segments.get(i) {
+            Some(&(range, dest)) if range.contains(value) => shift(value, range.start(), dest),
+            _ => value,
+        }
+    }
+
+    /// every segment plus the identity gaps between them, covering the whole domain
+    fn pieces(&self) -> Vec<(RangeSet<T>, T)> {
+        let mut result = Vec::with_capacity(self.segments.len() * 2 + 1);
+        let mut cursor = Some(T::MIN);
+        for &(source, dest) in &self.segments {
+            let Some(from) = cursor else { break };
+            if source.start() > from {
+                result.push((RangeSet::new(from, source.start() - T::ONE), from));
+            }
+            result.push((source, dest));
+            cursor = (source.end() < T::MAX).then(|| source.end() + T::ONE);
+        }
+        if let Some(from) = cursor {
+            result.push((RangeSet::new(from, T::MAX), from));
+        }
+        result
+    }
+
+    /// splits `range` at segment boundaries, pairing each part with its destination start
+    fn split(&self, range: RangeSet<T>) -> impl Iterator<Item = (RangeSet<T>, T)> {
+        self.pieces().into_iter().filter_map(move |(source, dest)| {
+            let part = (source & range)?;
+            Some((part, translate(part, source, dest).start()))
+        })
+    }
+
+    /// the image of `range`, split wherever it crosses a segment boundary
+    pub fn apply_range(&self, range: impl Into<RangeSet<T>>) -> RangeSetUnion<T> {
+        self.split(range.into())
+            .map(|(part, dest)| translate(part, part, dest))
+            .collect()
+    }
+
+    /// the image of every range in `set`
+    pub fn apply(&self, set: &RangeSetUnion<T>) -> RangeSetUnion<T> {
+        let pieces = self.pieces();
+        set.ranges()
+            .iter()
+            .flat_map(|&range| {
+                pieces.iter().filter_map(move |&(source, dest)| {
+                    let part = (source & range)?;
+                    Some(translate(part, source, dest))
+                })
+            })
+            .collect()
+    }
+
+    /// The map that applies `self` and then `then`.
+    pub fn compose(&self, then: &RangeMap<T>) -> RangeMap<T> {
+        let mut pieces = Vec::new();
+        for (source, dest) in self.pieces() {
+            let image = translate(source, source, dest);
+            for (part, final_dest) in then.split(image) {
+                // the values of `source` that land on `part`
+                pieces.push((translate(part, image, source.start()), final_dest));
+            }
+        }
+        pieces.sort_unstable_by_key(|(source, _)| source.start());
+        Self::from_sorted_pieces(pieces)
+    }
+
+    /// The inverse map, or `None` if two values map to the same value.
+    pub fn invert(&self) -> Option<RangeMap<T>> {
+        let mut pieces = self
+            .pieces()
+            .into_iter()
+            .map(|(source, dest)| (translate(source, source, dest), source.start()))
+            .collect::<Vec<_>>();
+        pieces.sort_unstable_by_key(|(image, _)| image.start());
+        // the pieces cover the whole domain, so disjoint images cover it too
+        if pieces
+            .windows(2)
+            .any(|pair| pair[0].0.end() >= pair[1].0.start())
+        {
+            return None;
+        }
+        Some(Self::from_sorted_pieces(pieces))
+    }
+}
+
+impl<T: RangeBound> FromIterator<(RangeSet<T>, T)> for RangeMap<T> {
+    fn from_iter<I: IntoIterator<Item = (RangeSet<T>, T)>>(iter: I) -> Self {
+        let mut map = RangeMap::new();
+        for (source, dest) in iter {
+            map.insert(source, dest);
+        }
+        map
+    }
+}
+
+impl<T: Display> Debug for RangeMap<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RangeMap{{")?;
+        for (i, (source, dest)) in self.segments.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{source} -> {dest}")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// the maps from the 2023 day 5 example, as `(dest, source, length)` lines
+    const ALMANAC: [&[(u64, u64, u64)]; 7] = [
+        &[(50, 98, 2), (52, 50, 48)],
+        &[(0, 15, 37), (37, 52, 2), (39, 0, 15)],
+        &[(49, 53, 8), (0, 11, 42), (42, 0, 7), (57, 7, 4)],
+        &[(88, 18, 7), (18, 25, 70)],
+        &[(45, 77, 23), (81, 45, 19), (68, 64, 13)],
+        &[(0, 69, 1), (1, 0, 69)],
+        &[(60, 56, 37), (56, 93, 4)],
+    ];
+
+    fn almanac() -> Vec<RangeMap> {
+        ALMANAC
+            .iter()
+            .map(|lines| {
+                lines
+                    .iter()
+                    .map(|&(dest, source, len)| (RangeSet::new(source, source + len - 1), dest))
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn get() {
+        let seed_to_soil = &almanac()[0];
+        assert_eq!(seed_to_soil.get(79), 81);
+        assert_eq!(seed_to_soil.get(14), 14);
+        assert_eq!(seed_to_soil.get(98), 50);
+        assert_eq!(seed_to_soil.get(99), 51);
+        assert_eq!(seed_to_soil.get(100), 100);
+        assert_eq!(
+            format!("{seed_to_soil:?}"),
+            "RangeMap{[50, 97] -> 52, [98, 99] -> 50}"
+        );
+    }
+
+    #[test]
+    fn aoc2023_day5_example() {
+        let maps = almanac();
+        let seed_to_location = maps
+            .iter()
+            .fold(RangeMap::new(), |acc, map| acc.compose(map));
+
+        for seed in 0..120 {
+            let stepwise = maps.iter().fold(seed, |value, map| map.get(value));
+            assert_eq!(seed_to_location.get(seed), stepwise);
+        }
+
+        assert_eq!(
+            [79, 14, 55, 13].map(|seed| seed_to_location.get(seed)),
+            [82, 43, 86, 35]
+        );
+
+        let seeds: RangeSetUnion = [RangeSet::new(79, 92), RangeSet::new(55, 67)]
+            .into_iter()
+            .collect();
+        let locations = seed_to_location.apply(&seeds);
+        assert_eq!(locations.min(), Some(46));
+        assert_eq!(locations.len(), seeds.len());
+        assert_eq!(
+            maps.iter()
+                .fold(seeds, |ranges, map| map.apply(&ranges))
+                .min(),
+            Some(46)
+        );
+    }
+
+    #[test]
+    fn apply_range_splits_at_boundaries() {
+        let map: RangeMap<u64> = [(RangeSet::new(10, 19), 100), (RangeSet::new(20, 24), 0)]
+            .into_iter()
+            .collect();
+        let image = map.apply_range((5, 22));
+        assert_eq!(
+            image,
+            [
+                RangeSet::new(0, 2),
+                RangeSet::new(5, 9),
+                RangeSet::new(100, 109)
+            ]
+            .into_iter()
+            .collect()
+        );
+    }
+
+    #[test]
+    fn compose_merges_and_cancels() {
+        let swap = RangeMap::from_iter([(RangeSet::new(0, 9), 100), (RangeSet::new(100, 109), 0)]);
+        assert!(swap.compose(&swap).is_identity());
+        assert_eq!(swap.invert(), Some(swap.clone()));
+
+        // the second half of the first map is undone, leaving only the first half
+        let first = RangeMap::from_iter([(RangeSet::new(0, 9), 100), (RangeSet::new(100, 109), 0)]);
+        let second =
+            RangeMap::from_iter([(RangeSet::new(105, 109), 5), (RangeSet::new(5, 9), 105)]);
+        assert_eq!(
+            first.compose(&second).segments(),
+            &[(RangeSet::new(0, 4), 100), (RangeSet::new(100, 104), 0)]
+        );
+
+        let mut joined = RangeMap::from_iter([(RangeSet::new(0, 4), 100)]);
+        joined.insert((5, 9), 105);
+        assert_eq!(joined.segments(), &[(RangeSet::new(0, 9), 100)]);
+    }
+
+    #[test]
+    fn invert() {
+        for map in almanac() {
+            let inverse = map.invert().expect("almanac maps are bijective");
+            assert!(map.compose(&inverse).is_identity());
+            assert!(inverse.compose(&map).is_identity());
+        }
+
+        let collapsing = RangeMap::from_iter([(RangeSet::new(0u8, 9), 10)]);
+        assert_eq!(collapsing.invert(), None);
+
+        let extremes =
+            RangeMap::from_iter([(RangeSet::new(0u8, 0), 255), (RangeSet::new(255u8, 255), 0)]);
+        assert_eq!(extremes.invert(), Some(extremes.clone()));
+    }
+
+    #[test]
+    #[should_panic(expected = "already mapped")]
+    fn insert_overlap_panics() {
+        let mut map = RangeMap::new();
+        map.insert((0, 10), 20);
+        map.insert((10, 12), 40);
+    }
+
+    #[test]
+    #[should_panic(expected = "already mapped")]
+    fn insert_overlapping_identity_panics() {
+        let mut map = RangeMap::new();
+        map.insert((0, 10), 0);
+        assert!(map.is_identity());
+        map.insert((5, 6), 40);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows")]
+    fn insert_past_max_panics() {
+        let mut map = RangeMap::<u8>::new();
+        map.insert((10, 20), 250);
+    }
+
+    #[test]
+    fn insert_near_the_edges() {
+        let mut map = RangeMap::<u8>::new();
+        map.insert((10, 20), 245);
+        assert_eq!(map.get(20), 255);
+
+        let mut signed = RangeMap::<i8>::new();
+        signed.insert((-128, -128), 127);
+        signed.insert((-127, 0), -1);
+        assert_eq!(signed.get(-128), 127);
+        assert_eq!(signed.get(0), 126);
+        assert!(!fits(RangeSet::new(-128i8, -1), 1));
+        assert!(fits(RangeSet::new(-128i8, -1), 0));
+        assert!(!fits(RangeSet::new(-10i8, 100), 100));
+    }
+}
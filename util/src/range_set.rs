@@ -1,14 +1,52 @@
 use paste::paste;
-use std::{fmt::Debug, fmt::Display, ops::RangeInclusive};
+use std::{
+    fmt::Debug,
+    fmt::Display,
+    hash::Hash,
+    ops::{Add, Mul, RangeInclusive, Sub},
+};
+
+/// Integer types that can be the bounds of a [`RangeSet`]
+pub trait RangeBound:
+    Copy + Ord + Hash + Debug + Display + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const MIN: Self;
+    const MAX: Self;
+
+    /// `self - rhs`, or `None` if that is outside the type
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_range_bound {
+    ($($t:ty),*) => {
+        $(
+            impl RangeBound for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+            }
+        )*
+    };
+}
 
+impl_range_bound!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// A closed interval `[from, to]` of integers
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
-pub struct RangeSet {
-    from: u64,
-    to: u64,
+pub struct RangeSet<T = u64> {
+    from: T,
+    to: T,
 }
 
-impl RangeSet {
-    pub fn new(from: u64, to: u64) -> RangeSet {
+impl<T: RangeBound> RangeSet<T> {
+    pub fn new(from: T, to: T) -> RangeSet<T> {
         assert!(
             from <= to,
             "from must be less than or equal to to, but received [{from}, {to}]",
@@ -16,11 +54,19 @@ impl RangeSet {
         RangeSet { from, to }
     }
 
-    pub fn contains(&self, value: u64) -> bool {
+    pub fn start(&self) -> T {
+        self.from
+    }
+
+    pub fn end(&self) -> T {
+        self.to
+    }
+
+    pub fn contains(&self, value: T) -> bool {
         value >= self.from && value <= self.to
     }
 
-    pub fn intersection(&self, other: &RangeSet) -> Option<RangeSet> {
+    pub fn intersection(&self, other: &RangeSet<T>) -> Option<RangeSet<T>> {
         if self.from > other.to || self.to < other.from {
             None
         } else {
@@ -31,7 +77,7 @@ impl RangeSet {
         }
     }
 
-    pub fn union(&self, other: &RangeSet) -> Option<RangeSet> {
+    pub fn union(&self, other: &RangeSet<T>) -> Option<RangeSet<T>> {
         if self.from > other.to || self.to < other.from {
             None
         } else {
@@ -42,37 +88,38 @@ impl RangeSet {
         }
     }
 
-    pub fn len(&self) -> u64 {
-        self.to - self.from + 1
+    pub fn len(&self) -> T {
+        self.to - self.from + T::ONE
     }
 
-    pub fn iter(&self) -> impl Iterator<Item = u64> {
-        (self.from..=self.to).into_iter()
+    pub fn iter(&self) -> impl Iterator<Item = T> {
+        let to = self.to;
+        std::iter::successors(Some(self.from), move |&v| (v < to).then(|| v + T::ONE))
     }
 
-    pub fn subset_greater_than(&self, value: u64) -> Option<RangeSet> {
+    pub fn subset_greater_than(&self, value: T) -> Option<RangeSet<T>> {
         if value >= self.to {
             None
         } else {
             Some(RangeSet {
-                from: value + 1,
+                from: value + T::ONE,
                 to: self.to,
             })
         }
     }
 
-    pub fn subset_less_than(&self, value: u64) -> Option<RangeSet> {
+    pub fn subset_less_than(&self, value: T) -> Option<RangeSet<T>> {
         if value <= self.from {
             None
         } else {
             Some(RangeSet {
                 from: self.from,
-                to: value - 1,
+                to: value - T::ONE,
             })
         }
     }
 
-    pub fn subset_greater_than_or_equal(&self, value: u64) -> Option<RangeSet> {
+    pub fn subset_greater_than_or_equal(&self, value: T) -> Option<RangeSet<T>> {
         if value > self.to {
             None
         } else {
@@ -83,7 +130,7 @@ impl RangeSet {
         }
     }
 
-    pub fn subset_less_than_or_equal(&self, value: u64) -> Option<RangeSet> {
+    pub fn subset_less_than_or_equal(&self, value: T) -> Option<RangeSet<T>> {
         if value < self.from {
             None
         } else {
@@ -94,14 +141,20 @@ impl RangeSet {
         }
     }
 
-    pub fn partition_upper_inclusive(&self, value: u64) -> (Option<RangeSet>, Option<RangeSet>) {
+    pub fn partition_upper_inclusive(
+        &self,
+        value: T,
+    ) -> (Option<RangeSet<T>>, Option<RangeSet<T>>) {
         (
             self.subset_less_than(value),
             self.subset_greater_than_or_equal(value),
         )
     }
 
-    pub fn partition_lower_inclusive(&self, value: u64) -> (Option<RangeSet>, Option<RangeSet>) {
+    pub fn partition_lower_inclusive(
+        &self,
+        value: T,
+    ) -> (Option<RangeSet<T>>, Option<RangeSet<T>>) {
         (
             self.subset_less_than_or_equal(value),
             self.subset_greater_than(value),
@@ -109,32 +162,32 @@ impl RangeSet {
     }
 }
 
-impl Debug for RangeSet {
+impl<T: Display> Debug for RangeSet<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "RangeSet[{}, {}]", self.from, self.to)
     }
 }
 
-impl Display for RangeSet {
+impl<T: Display> Display for RangeSet<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "[{}, {}]", self.from, self.to)
     }
 }
 
-impl From<(u64, u64)> for RangeSet {
-    fn from((from, to): (u64, u64)) -> Self {
+impl<T: RangeBound> From<(T, T)> for RangeSet<T> {
+    fn from((from, to): (T, T)) -> Self {
         RangeSet::new(from, to)
     }
 }
 
-impl From<RangeSet> for (u64, u64) {
-    fn from(range_set: RangeSet) -> Self {
+impl<T> From<RangeSet<T>> for (T, T) {
+    fn from(range_set: RangeSet<T>) -> Self {
         (range_set.from, range_set.to)
     }
 }
 
-impl From<RangeInclusive<u64>> for RangeSet {
-    fn from(range: RangeInclusive<u64>) -> Self {
+impl<T: RangeBound> From<RangeInclusive<T>> for RangeSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
         RangeSet::new(*range.start(), *range.end())
     }
 }
@@ -144,34 +197,34 @@ impl From<RangeInclusive<u64>> for RangeSet {
 macro_rules! impl_set_operator {
     ($trait:ident, $method:ident) => {
         paste! {
-            impl std::ops::$trait<RangeSet> for RangeSet {
-                type Output = Option<RangeSet>;
+            impl<T: RangeBound> std::ops::$trait<RangeSet<T>> for RangeSet<T> {
+                type Output = Option<RangeSet<T>>;
 
                 fn [<$trait:lower>](self, rhs: Self) -> Self::Output {
                     self.$method(&rhs)
                 }
             }
 
-            impl std::ops::$trait<&RangeSet> for RangeSet {
-                type Output = Option<RangeSet>;
+            impl<T: RangeBound> std::ops::$trait<&RangeSet<T>> for RangeSet<T> {
+                type Output = Option<RangeSet<T>>;
 
                 fn [<$trait:lower>](self, rhs: &Self) -> Self::Output {
                     self.$method(rhs)
                 }
             }
 
-            impl std::ops::$trait<RangeSet> for &RangeSet {
-                type Output = Option<RangeSet>;
+            impl<T: RangeBound> std::ops::$trait<RangeSet<T>> for &RangeSet<T> {
+                type Output = Option<RangeSet<T>>;
 
-                fn [<$trait:lower>](self, rhs: RangeSet) -> Self::Output {
+                fn [<$trait:lower>](self, rhs: RangeSet<T>) -> Self::Output {
                     self.$method(&rhs)
                 }
             }
 
-            impl std::ops::$trait<&RangeSet> for &RangeSet {
-                type Output = Option<RangeSet>;
+            impl<T: RangeBound> std::ops::$trait<&RangeSet<T>> for &RangeSet<T> {
+                type Output = Option<RangeSet<T>>;
 
-                fn [<$trait:lower>](self, rhs: &RangeSet) -> Self::Output {
+                fn [<$trait:lower>](self, rhs: &RangeSet<T>) -> Self::Output {
                     self.$method(rhs)
                 }
             }
@@ -231,4 +284,21 @@ mod tests {
         assert_eq!(a.union(&b), Some(RangeSet { from: 0, to: 15 }));
         assert_eq!(a.union(&c), None);
     }
+
+    #[test]
+    fn range_set_signed() {
+        let a = RangeSet::new(-5i64, 5);
+        let b = RangeSet::new(-10i64, -3);
+
+        assert_eq!(a & b, Some(RangeSet::new(-5, -3)));
+        assert_eq!(a.len(), 11);
+        assert_eq!(
+            b.iter().collect::<Vec<_>>(),
+            vec![-10, -9, -8, -7, -6, -5, -4, -3]
+        );
+        assert_eq!(
+            a.partition_upper_inclusive(0),
+            (Some(RangeSet::new(-5, -1)), Some(RangeSet::new(0, 5)))
+        );
+    }
 }
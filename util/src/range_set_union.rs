@@ -0,0 +1,287 @@
+use paste::paste;
+use std::fmt::{Debug, Display};
+
+use crate::{RangeBound, RangeSet};
+
+/// Any number of disjoint [`RangeSet`]s.
+///
+/// Kept sorted, with overlapping and adjacent ranges merged, so two unions holding the
+/// same values always compare equal.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct RangeSetUnion<T = u64> {
+    ranges: Vec<RangeSet<T>>,
+}
+
+/// whether `b_from` starts within or directly after a range ending at `a_to`
+fn touches<T: RangeBound>(a_to: T, b_from: T) -> bool {
+    b_from <= a_to || b_from - T::ONE <= a_to
+}
+
+impl<T: RangeBound> Default for RangeSetUnion<T> {
+    fn default() -> Self {
+        RangeSetUnion { ranges: Vec::new() }
+    }
+}
+
+impl<T: RangeBound> RangeSetUnion<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn normalized(mut ranges: Vec<RangeSet<T>>) -> Self {
+        ranges.sort_unstable_by_key(RangeSet::start);
+        let mut merged: Vec<RangeSet<T>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if touches(last.end(), range.start()) => {
+                    *last = RangeSet::new(last.start(), last.end().max(range.end()));
+                }
+                _ => merged.push(range),
+            }
+        }
+        RangeSetUnion { ranges: merged }
+    }
+
+    /// the disjoint ranges, in increasing order
+    pub fn ranges(&self) -> &[RangeSet<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// total number of values in the union
+    pub fn len(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::ZERO, |acc, range| acc + range.len())
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(RangeSet::start)
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.ranges.last().map(RangeSet::end)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|range| range.end() < value);
+        self.ranges
+            .get(i)
+            .is_some_and(|range| range.contains(value))
+    }
+
+    pub fn insert(&mut self, range: impl Into<RangeSet<T>>) {
+        let mut ranges = std::mem::take(&mut self.ranges);
+        ranges.push(range.into());
+        *self = Self::normalized(ranges);
+    }
+
+    pub fn union(&self, other: &RangeSetUnion<T>) -> RangeSetUnion<T> {
+        Self::normalized(
+            self.ranges
+                .iter()
+                .chain(other.ranges.iter())
+                .copied()
+                .collect(),
+        )
+    }
+
+    pub fn intersection(&self, other: &RangeSetUnion<T>) -> RangeSetUnion<T> {
+        let mut result = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (self.ranges[i], other.ranges[j]);
+            if let Some(overlap) = a & b {
+                result.push(overlap);
+            }
+            if a.end() < b.end() {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        // already sorted, but may touch where both inputs had adjacent ranges
+        Self::normalized(result)
+    }
+
+    /// the values of `within` that are not in `self`
+    pub fn complement(&self, within: RangeSet<T>) -> RangeSetUnion<T> {
+        let mut result = Vec::new();
+        let mut cursor = Some(within.start());
+        for range in self.ranges.iter().filter_map(|range| range & within) {
+            let Some(from) = cursor else { break };
+            if range.start() > from {
+                result.push(RangeSet::new(from, range.start() - T::ONE));
+            }
+            cursor = (range.end() < within.end()).then(|| range.end() + T::ONE);
+        }
+        if let Some(from) = cursor {
+            result.push(RangeSet::new(from, within.end()));
+        }
+        RangeSetUnion { ranges: result }
+    }
+
+    pub fn difference(&self, other: &RangeSetUnion<T>) -> RangeSetUnion<T> {
+        match (self.min(), self.max()) {
+            (Some(min), Some(max)) => self.intersection(&other.complement(RangeSet::new(min, max))),
+            _ => RangeSetUnion::new(),
+        }
+    }
+
+    /// every value in the union, in increasing order
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        self.ranges.iter().flat_map(RangeSet::iter)
+    }
+}
+
+impl<T: RangeBound> FromIterator<RangeSet<T>> for RangeSetUnion<T> {
+    fn from_iter<I: IntoIterator<Item = RangeSet<T>>>(iter: I) -> Self {
+        Self::normalized(iter.into_iter().collect())
+    }
+}
+
+impl<T: RangeBound> From<RangeSet<T>> for RangeSetUnion<T> {
+    fn from(range: RangeSet<T>) -> Self {
+        RangeSetUnion {
+            ranges: vec![range],
+        }
+    }
+}
+
+impl<T: Display> Debug for RangeSetUnion<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RangeSetUnion{{")?;
+        for (i, range) in self.ranges.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{range}")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+// operators
+
+macro_rules! impl_set_operator {
+    ($trait:ident, $method:ident) => {
+        paste! {
+            impl<T: RangeBound> std::ops::$trait<RangeSetUnion<T>> for RangeSetUnion<T> {
+                type Output = RangeSetUnion<T>;
+
+                fn [<$trait:lower>](self, rhs: Self) -> Self::Output {
+                    self.$method(&rhs)
+                }
+            }
+
+            impl<T: RangeBound> std::ops::$trait<&RangeSetUnion<T>> for RangeSetUnion<T> {
+                type Output = RangeSetUnion<T>;
+
+                fn [<$trait:lower>](self, rhs: &Self) -> Self::Output {
+                    self.$method(rhs)
+                }
+            }
+
+            impl<T: RangeBound> std::ops::$trait<RangeSetUnion<T>> for &RangeSetUnion<T> {
+                type Output = RangeSetUnion<T>;
+
+                fn [<$trait:lower>](self, rhs: RangeSetUnion<T>) -> Self::Output {
+                    self.$method(&rhs)
+                }
+            }
+
+            impl<T: RangeBound> std::ops::$trait<&RangeSetUnion<T>> for &RangeSetUnion<T> {
+                type Output = RangeSetUnion<T>;
+
+                fn [<$trait:lower>](self, rhs: &RangeSetUnion<T>) -> Self::Output {
+                    self.$method(rhs)
+                }
+            }
+        }
+    };
+}
+
+impl_set_operator!(BitAnd, intersection);
+impl_set_operator!(BitOr, union);
+impl_set_operator!(Sub, difference);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn union<T: RangeBound>(ranges: &[(T, T)]) -> RangeSetUnion<T> {
+        ranges.iter().map(|&r| RangeSet::from(r)).collect()
+    }
+
+    #[test]
+    fn normalizes_overlapping_and_adjacent() {
+        let a = union(&[(10, 20), (0, 5), (6, 8), (15, 30), (40, 40)]);
+        assert_eq!(a.ranges(), union(&[(0, 8), (10, 30), (40, 40)]).ranges());
+        assert_eq!(a.len(), 9 + 21 + 1);
+        assert_eq!(
+            format!("{a:?}"),
+            "RangeSetUnion{[0, 8], [10, 30], [40, 40]}"
+        );
+    }
+
+    #[test]
+    fn contains() {
+        let a = union(&[(0, 5), (10, 15)]);
+        assert!(a.contains(0));
+        assert!(a.contains(5));
+        assert!(!a.contains(7));
+        assert!(a.contains(12));
+        assert!(!a.contains(16));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = union(&[(0, 10), (20, 30)]);
+        let b = union(&[(5, 25)]);
+
+        assert_eq!(&a | &b, union(&[(0, 30)]));
+        assert_eq!(&a & &b, union(&[(5, 10), (20, 25)]));
+        assert_eq!(&a - &b, union(&[(0, 4), (26, 30)]));
+        assert_eq!(&b - &a, union(&[(11, 19)]));
+        assert_eq!(
+            a.complement(RangeSet::new(0, 40)),
+            union(&[(11, 19), (31, 40)])
+        );
+        assert_eq!(a.complement(RangeSet::new(5, 25)), union(&[(11, 19)]));
+        assert_eq!(
+            RangeSetUnion::new().complement(RangeSet::new(1u64, 2)),
+            union(&[(1, 2)])
+        );
+    }
+
+    #[test]
+    fn signed_and_extreme_bounds() {
+        let a = union(&[(i64::MIN, -10), (10, i64::MAX)]);
+        let full = RangeSet::new(i64::MIN, i64::MAX);
+
+        assert_eq!(a.complement(full), union(&[(-9, 9)]));
+        assert_eq!(
+            union(&[(-9, 9)]).complement(full),
+            union(&[(i64::MIN, -10), (10, i64::MAX)])
+        );
+        assert_eq!(&a | &union(&[(-9, 9)]), union(&[(i64::MIN, i64::MAX)]));
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let a = union(&[(3, 9), (12, 14), (30, 31), (40, 55)]);
+        let b = union(&[(0, 4), (9, 13), (31, 45), (50, 50)]);
+        let within = RangeSet::new(0, 60);
+
+        for v in 0..=60 {
+            assert_eq!((&a | &b).contains(v), a.contains(v) || b.contains(v));
+            assert_eq!((&a & &b).contains(v), a.contains(v) && b.contains(v));
+            assert_eq!((&a - &b).contains(v), a.contains(v) && !b.contains(v));
+            assert_eq!(a.complement(within).contains(v), !a.contains(v));
+        }
+        assert_eq!(a.iter().count() as u64, a.len());
+    }
+}